@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::solution::Answer;
+
+/// The key for answers to the unnamed input, `dayNN.txt`.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// Where the `YEAR/dayNN.toml` answer files live.
+pub fn answers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers")
+}
+
+/// Whether a rejected submission was too high or too low.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hint {
+    High,
+    Low,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Value> for Answer {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(n) => Answer::Number(n as i128),
+            Value::Text(s) => Answer::Text(s),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WrongFile {
+    value: Value,
+    hint: Option<Hint>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartFile {
+    answer: Option<Value>,
+    #[serde(default)]
+    wrong: Vec<WrongFile>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InputFile {
+    part1: Option<PartFile>,
+    part2: Option<PartFile>,
+}
+
+/// What is known about one part: the confirmed answer, if any, and submissions that were
+/// rejected.
+#[derive(Clone, Debug, Default)]
+pub struct Known {
+    pub answer: Option<Answer>,
+    pub wrong: Vec<(Answer, Option<Hint>)>,
+}
+
+impl From<PartFile> for Known {
+    fn from(part: PartFile) -> Self {
+        Known {
+            answer: part.answer.map(Answer::from),
+            wrong: part
+                .wrong
+                .into_iter()
+                .map(|w| (w.value.into(), w.hint))
+                .collect(),
+        }
+    }
+}
+
+/// How a result compares with what is recorded for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// No confirmed answer, but this exact value was already rejected.
+    KnownWrong,
+    /// No confirmed answer, but a rejected submission shows this value is too high or too low.
+    OutOfBounds {
+        bound: Answer,
+        hint: Hint,
+    },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Pass | Verdict::Unknown)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::KnownWrong => write!(f, "FAIL (already rejected)"),
+            Verdict::OutOfBounds { bound, hint: Hint::High } => {
+                write!(f, "FAIL (too high, must be below {bound})")
+            }
+            Verdict::OutOfBounds { bound, hint: Hint::Low } => {
+                write!(f, "FAIL (too low, must be above {bound})")
+            }
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The recorded answers for one day, keyed by input name.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    inputs: HashMap<String, [Known; 2]>,
+}
+
+impl Answers {
+    /// Load `YEAR/dayNN.toml` from the answers directory. A day with no file has no known answers.
+    pub fn load(year: u32, day: u32) -> Result<Self> {
+        let path = answers_dir()
+            .join(year.to_string())
+            .join(format!("day{day:02}.toml"));
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        Answers::parse(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: HashMap<String, InputFile> = toml::from_str(text)?;
+        let inputs = file
+            .into_iter()
+            .map(|(name, input)| {
+                let part1 = input.part1.map(Known::from).unwrap_or_default();
+                let part2 = input.part2.map(Known::from).unwrap_or_default();
+                (name, [part1, part2])
+            })
+            .collect();
+        Ok(Answers { inputs })
+    }
+
+    pub fn known(&self, input: Option<&str>, part: u32) -> Option<&Known> {
+        let parts = self.inputs.get(input.unwrap_or(DEFAULT_INPUT))?;
+        parts.get((part as usize).checked_sub(1)?)
+    }
+
+    pub fn expected(&self, input: Option<&str>, part: u32) -> Option<&Answer> {
+        self.known(input, part)?.answer.as_ref()
+    }
+
+    pub fn check(&self, input: Option<&str>, part: u32, answer: &Answer) -> Verdict {
+        let Some(known) = self.known(input, part) else {
+            return Verdict::Unknown;
+        };
+        if let Some(expected) = &known.answer {
+            return if expected == answer {
+                Verdict::Pass
+            } else {
+                Verdict::Fail { expected: expected.clone() }
+            };
+        }
+        for (wrong, hint) in &known.wrong {
+            if wrong == answer {
+                return Verdict::KnownWrong;
+            }
+            let (Answer::Number(value), Answer::Number(bound)) = (answer, wrong) else {
+                continue;
+            };
+            match *hint {
+                Some(Hint::High) if value < bound => {}
+                Some(Hint::Low) if value > bound => {}
+                Some(hint) => return Verdict::OutOfBounds { bound: wrong.clone(), hint },
+                None => {}
+            }
+        }
+        Verdict::Unknown
+    }
+}
+
+/// The confirmed answer for a day's default input, for the real-input tests.
+#[cfg(test)]
+pub(crate) fn expected(year: u32, day: u32, part: u32) -> Answer {
+    let answers = Answers::load(year, day).unwrap();
+    answers.expected(None, part).cloned().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Hint, Verdict};
+    use crate::solution::Answer;
+
+    const ANSWERS: &str = r#"
+[default.part1]
+answer = 430
+wrong = [{ value = 435, hint = "high" }]
+
+[alice.part2]
+wrong = [{ value = 5242, hint = "low" }, { value = 82782, hint = "high" }, { value = 6000 }]
+"#;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(None, 1, &Answer::from(430)), Verdict::Pass);
+        assert_eq!(
+            answers.check(None, 1, &Answer::from(435)),
+            Verdict::Fail { expected: Answer::from(430) }
+        );
+        assert_eq!(answers.check(None, 2, &Answer::from(1)), Verdict::Unknown);
+
+        let alice = Some("alice");
+        assert_eq!(
+            answers.check(alice, 2, &Answer::from(6802)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(alice, 2, &Answer::from(6000)),
+            Verdict::KnownWrong
+        );
+        assert_eq!(
+            answers.check(alice, 2, &Answer::from(5000)),
+            Verdict::OutOfBounds { bound: Answer::from(5242), hint: Hint::Low }
+        );
+        assert_eq!(
+            answers.check(alice, 2, &Answer::from(82782)),
+            Verdict::KnownWrong
+        );
+        assert_eq!(
+            answers.check(alice, 2, &Answer::from(90000)),
+            Verdict::OutOfBounds { bound: Answer::from(82782), hint: Hint::High }
+        );
+    }
+
+    #[test]
+    fn test_answer_files() {
+        for day in 1..=25 {
+            let answers = Answers::load(2023, day).unwrap();
+            assert!(answers.expected(None, 1).is_some(), "day {day}");
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::solution::AnySolution;
+use crate::table;
+
+/// How long to keep sampling each stage. Every stage is run at least once, however slow.
+#[derive(Copy, Clone, Debug)]
+pub struct Budget {
+    pub time: Duration,
+    pub max_samples: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget { time: Duration::from_secs(1), max_samples: 100 }
+    }
+}
+
+/// Summary statistics over the samples of one stage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median =
+            if n % 2 == 1 { sorted[n / 2] } else { (sorted[n / 2 - 1] + sorted[n / 2]) / 2 };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = if n > 1 {
+            let mean = mean.as_secs_f64();
+            sorted
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.
+        };
+        Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean,
+            max: sorted[n - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u32),
+}
+
+impl Stage {
+    /// The key used for this stage in a baseline file.
+    fn key(self) -> String {
+        match self {
+            Stage::Parse => "parse".to_string(),
+            Stage::Part(part) => format!("part{part}"),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Run `f` repeatedly until the budget runs out and summarise how long each run took.
+pub fn sample<T>(budget: &Budget, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    while samples.is_empty()
+        || (samples.len() < budget.max_samples && start.elapsed() < budget.time)
+    {
+        let run = Instant::now();
+        black_box(f()?);
+        samples.push(run.elapsed());
+    }
+    Ok(Stats::new(&samples))
+}
+
+/// Time parsing the input, then each part from the parsed input.
+pub fn bench_day(
+    year: u32,
+    solution: &dyn AnySolution,
+    input: &str,
+    params: &[(String, String)],
+    budget: &Budget,
+) -> Result<Vec<Timing>> {
+    let day = solution.day();
+    let stats = sample(budget, || solution.parse(input))?;
+    let mut res = vec![Timing { year, day, stage: Stage::Parse, stats }];
+
+    let parsed = solution.parse(input)?;
+    for &part in solution.parts() {
+        let stats = sample(budget, || solution.solve(parsed.as_ref(), part, params))?;
+        res.push(Timing { year, day, stage: Stage::Part(part), stats });
+    }
+    Ok(res)
+}
+
+/// Median timings from an earlier run, stored as nanoseconds keyed by year, day and stage:
+///
+/// ```toml
+/// [2023-day17]
+/// parse = 81200
+/// part1 = 61250000
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+/// How a timing compares with the baseline.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Change {
+    /// Nothing recorded for this day and stage.
+    New,
+    /// The change in the median as a fraction of the baseline, e.g. 0.25 for 25% slower.
+    Within(f64),
+    Regression(f64),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Within(change) => write!(f, "{:+.1}%", change * 100.),
+            Change::Regression(change) => write!(f, "{:+.1}% REGRESSION", change * 100.),
+        }
+    }
+}
+
+fn day_key(year: u32, day: u32) -> String {
+    format!("{year}-day{day:02}")
+}
+
+impl Baseline {
+    pub fn from_timings(timings: &[Timing]) -> Self {
+        let mut medians: BTreeMap<String, BTreeMap<String, u64>> = BTreeMap::new();
+        for timing in timings {
+            medians
+                .entry(day_key(timing.year, timing.day))
+                .or_default()
+                .insert(timing.stage.key(), timing.stats.median.as_nanos() as u64);
+        }
+        Baseline { medians }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        Ok(Baseline { medians: toml::from_str(text)? })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        Baseline::parse(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(&self.medians)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn median(&self, year: u32, day: u32, stage: Stage) -> Option<Duration> {
+        let nanos = self.medians.get(&day_key(year, day))?.get(&stage.key())?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    /// Compare medians. Anything slower than the baseline by more than `threshold`, as a
+    /// fraction, is a regression.
+    pub fn compare(&self, timing: &Timing, threshold: f64) -> Change {
+        let Some(baseline) = self.median(timing.year, timing.day, timing.stage) else {
+            return Change::New;
+        };
+        let baseline = baseline.as_secs_f64().max(f64::MIN_POSITIVE);
+        let change = timing.stats.median.as_secs_f64() / baseline - 1.;
+        if change > threshold {
+            Change::Regression(change)
+        } else {
+            Change::Within(change)
+        }
+    }
+}
+
+/// Durations to three significant figures in whichever unit suits them.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let precision = if value < 10. {
+        2
+    } else if value < 100. {
+        1
+    } else {
+        0
+    };
+    format!("{value:.precision$} {unit}")
+}
+
+/// Lay the timings out as a table, either aligned plain text or markdown, with a column comparing
+/// against the baseline when there is one.
+pub fn render(timings: &[Timing], baseline: Option<(&Baseline, f64)>, markdown: bool) -> String {
+    let mut header = [
+        "Day", "Stage", "Samples", "Min", "Median", "Mean", "Max", "Std dev",
+    ]
+    .map(String::from)
+    .to_vec();
+    if baseline.is_some() {
+        header.push("vs baseline".to_string());
+    }
+    let mut rows = vec![header];
+    for timing in timings {
+        let stats = &timing.stats;
+        let mut row = vec![
+            format!("{:02}", timing.day),
+            timing.stage.to_string(),
+            stats.samples.to_string(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.max),
+            format_duration(stats.std_dev),
+        ];
+        if let Some((baseline, threshold)) = baseline {
+            row.push(baseline.compare(timing, threshold).to_string());
+        }
+        rows.push(row);
+    }
+    table::render(&rows, markdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_duration, render, Baseline, Change, Stage, Stats, Timing};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn timing(day: u32, stage: Stage, median: u64) -> Timing {
+        Timing {
+            year: 2023,
+            day,
+            stage,
+            stats: Stats::new(&[ms(median)]),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&[ms(4), ms(1), ms(3), ms(2)]);
+        assert_eq!(stats.samples, 4);
+        assert_eq!((stats.min, stats.max), (ms(1), ms(4)));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.std_dev.as_micros(), 1290);
+        assert_eq!(Stats::new(&[ms(7)]).std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let before = [timing(3, Stage::Parse, 10), timing(3, Stage::Part(1), 100)];
+        let text = toml::to_string(&Baseline::from_timings(&before).medians).unwrap();
+        assert_eq!(text, "[2023-day03]\nparse = 10000000\npart1 = 100000000\n");
+
+        let baseline = Baseline::parse(&text).unwrap();
+        assert_eq!(baseline.median(2023, 3, Stage::Part(1)), Some(ms(100)));
+        assert!(matches!(
+            baseline.compare(&timing(3, Stage::Part(1), 105), 0.1),
+            Change::Within(change) if (change - 0.05).abs() < 1e-9
+        ));
+        assert_eq!(
+            baseline.compare(&timing(3, Stage::Parse, 15), 0.1),
+            Change::Regression(0.5)
+        );
+        assert_eq!(
+            baseline.compare(&timing(3, Stage::Part(2), 1), 0.1),
+            Change::New
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850 ns");
+        assert_eq!(format_duration(Duration::from_micros(1234)), "1.23 ms");
+        assert_eq!(format_duration(Duration::from_millis(45678)), "45.7 s");
+
+        let timings = [timing(1, Stage::Part(2), 2)];
+        let table = render(&timings, None, true);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(
+            lines[0].starts_with("| Day | Stage  | Samples |"),
+            "{table}"
+        );
+        assert!(
+            lines[1].starts_with("| --- | ------ | ------- |"),
+            "{table}"
+        );
+        assert!(
+            lines[2].starts_with("| 01  | part 2 | 1       | 2.00 ms |"),
+            "{table}"
+        );
+
+        let baseline = Baseline::from_timings(&[timing(1, Stage::Part(2), 1)]);
+        let table = render(&timings, Some((&baseline, 0.1)), false);
+        assert!(table.ends_with("+100.0% REGRESSION\n"), "{table}");
+    }
+}
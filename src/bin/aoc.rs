@@ -0,0 +1,539 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent_of_code::answers::{Answers, Verdict};
+use advent_of_code::bench::{self, Baseline, Budget};
+use advent_of_code::fetch::Provider;
+use advent_of_code::input::Inputs;
+use advent_of_code::runner;
+use advent_of_code::scaffold::{Example, Scaffold};
+use advent_of_code::solution::{Answer, AnySolution};
+use advent_of_code::viz;
+use anyhow::{bail, Context};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Puzzle year. Defaults to the latest year with solutions.
+    #[arg(long, global = true)]
+    year: Option<u32>,
+
+    /// Directory holding the puzzle inputs. Defaults to $AOC_INPUT_DIR, then the crate's input/.
+    /// Missing inputs are fetched into it from $AOC_MIRROR, or the site if $AOC_SESSION is set.
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// Log each day's debug output to stderr, or with -vv its trace output too. RUST_LOG, when
+    /// set, takes precedence, e.g. RUST_LOG=advent_of_code::year2023::day14=debug.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day (or every day) and print the answers.
+    Run(RunArgs),
+    /// Check answers against the ones recorded in answers/YEAR/dayNN.toml.
+    Verify(VerifyArgs),
+    /// Time parsing and each part, for one day or every day.
+    Bench(BenchArgs),
+    /// Create src/yearYYYY/dayNN.rs from the template and register it.
+    New(NewArgs),
+    /// Draw a day's grid, or each step of it, to PNG, PPM or animated GIF.
+    Viz(VizArgs),
+    /// Print a day's graph in Graphviz's DOT language, e.g. `aoc dot --day 20 | dot -Tsvg`.
+    Dot(DotArgs),
+    /// Print a random input for a day, e.g. `aoc gen --day 10 --seed 3 > day10.big.txt`.
+    Gen(GenArgs),
+    /// Print a day's answer to a side question, e.g. `aoc report --day 2 --param games=1,4,9`
+    /// for the smallest bag with which exactly those games are possible.
+    Report(ReportArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve, 1 through 25.
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Part to solve. Both parts are solved when omitted.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Puzzle input file. Defaults to dayNN.txt in the input directory.
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Read the named input dayNN.<NAME>.txt from the input directory instead, e.g. `alice`.
+    #[arg(long, conflicts_with = "input")]
+    input_name: Option<String>,
+
+    /// Solve every day with its default input, and print a summary.
+    #[arg(long)]
+    all: bool,
+
+    /// How many days to solve at once with --all. Defaults to one per CPU.
+    #[arg(long, conflicts_with = "day")]
+    jobs: Option<usize>,
+
+    /// How to print the summary from --all: `text` (the default) or `json`.
+    #[arg(long, conflicts_with = "day", value_enum)]
+    format: Option<Format>,
+
+    /// Override one of the day's parameters, e.g. `--param multiplier=10` for day 11.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param, conflicts_with = "all")]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify. Every day is verified when omitted.
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Verify the named input dayNN.<NAME>.txt instead of dayNN.txt.
+    #[arg(long)]
+    input_name: Option<String>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark. Every day with an input is benchmarked when omitted.
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Seconds to spend sampling each stage. Slow stages still run once.
+    #[arg(long, default_value_t = 1.0)]
+    seconds: f64,
+
+    /// Most samples to take of each stage.
+    #[arg(long, default_value_t = 100)]
+    max_samples: usize,
+
+    /// Print the results as a markdown table.
+    #[arg(long)]
+    markdown: bool,
+
+    /// Save the median timings to this file, to compare later runs against.
+    #[arg(long, value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+
+    /// Compare against a baseline saved with --save-baseline and fail on regressions.
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Percentage slowdown of the median that counts as a regression.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Benchmark a random input of this size, as made by `aoc gen`, instead of the puzzle input.
+    #[arg(long, value_name = "SIZE")]
+    generate: Option<usize>,
+
+    /// Seed for the inputs made with --generate.
+    #[arg(long, default_value_t = 0, requires = "generate")]
+    seed: u64,
+
+    /// Override one of the day's parameters, e.g. `--param tiled_steps=49` for a day 21 garden
+    /// made with `--generate 5`.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create, 1 through 25.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// File holding part 1's example input, written to the day's fixtures. Defaults to the first example
+    /// on the puzzle page when inputs can be fetched.
+    #[arg(long, value_name = "FILE")]
+    example1: Option<PathBuf>,
+
+    /// Part 1's answer for the example.
+    #[arg(long)]
+    answer1: Option<u64>,
+
+    /// File holding part 2's example input, if it differs from part 1's.
+    #[arg(long, value_name = "FILE")]
+    example2: Option<PathBuf>,
+
+    /// Part 2's answer for the example.
+    #[arg(long)]
+    answer2: Option<u64>,
+}
+
+#[derive(Args)]
+struct VizArgs {
+    /// Day to draw.
+    #[arg(long)]
+    day: u32,
+
+    /// Puzzle input file. Defaults to dayNN.txt in the input directory.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Image to write, as .png, .ppm or .gif. Defaults to dayNN.gif when the day draws several
+    /// frames, otherwise dayNN.png.
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Pixels per grid cell.
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    /// Milliseconds between the frames of a GIF.
+    #[arg(long, default_value_t = 100)]
+    delay_ms: u64,
+
+    /// Override one of the day's parameters, e.g. `--param steps=20` for day 21.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+struct DotArgs {
+    /// Day to print: 8, 19, 20, 22, 23 or 25.
+    #[arg(long)]
+    day: u32,
+
+    /// Puzzle input file. Defaults to dayNN.txt in the input directory.
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to make an input for.
+    #[arg(long)]
+    day: u32,
+
+    /// The same seed and size always make the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big an input to make. What it counts depends on the day, e.g. lines or the side of a
+    /// grid.
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// Day to report on: 2.
+    #[arg(long)]
+    day: u32,
+
+    /// Puzzle input file. Defaults to dayNN.txt in the input directory.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Read the named input dayNN.<NAME>.txt from the input directory instead, e.g. `alice`.
+    #[arg(long, conflicts_with = "input")]
+    input_name: Option<String>,
+
+    /// Set one of the day's parameters, e.g. `--param games=1,4,9` for day 2.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{s}`"))
+}
+
+fn parts(solution: &dyn AnySolution, part: Option<u32>) -> anyhow::Result<Vec<u32>> {
+    match part {
+        Some(part) if !solution.parts().contains(&part) => {
+            bail!("day {} has no part {part}", solution.day())
+        }
+        Some(part) => Ok(vec![part]),
+        None => Ok(solution.parts().to_vec()),
+    }
+}
+
+/// Recorded answers only apply to unmodified inputs solved with the default parameters.
+fn warn_if_wrong(answers: &Answers, day: u32, name: Option<&str>, part: u32, answer: &Answer) {
+    let verdict = answers.check(name, part, answer);
+    if verdict.is_failure() {
+        eprintln!("warning: day {day:02} part {part} answer {answer}: {verdict}");
+    }
+}
+
+/// Inputs from the input directory, fetching missing ones if `AOC_MIRROR` or `AOC_SESSION` is set.
+fn provider(input_dir: Option<&Path>) -> anyhow::Result<Provider> {
+    Ok(Provider::from_env(Inputs::locate(input_dir)?))
+}
+
+/// The given input file, or else the day's input from the input directory.
+fn read_input(
+    year: u32,
+    input_dir: Option<&Path>,
+    day: u32,
+    input: Option<&Path>,
+    name: Option<&str>,
+) -> anyhow::Result<String> {
+    match input {
+        Some(path) => {
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+        }
+        None => provider(input_dir)?.input(year, day, name),
+    }
+}
+
+/// The year's solution for one day, or for every day when no day is given.
+fn select(year: u32, day: Option<u32>) -> anyhow::Result<Vec<&'static dyn AnySolution>> {
+    let solutions: Vec<_> = match day {
+        Some(day) => runner::solution(year, day).into_iter().collect(),
+        None => runner::solutions(year).collect(),
+    };
+    if solutions.is_empty() {
+        match day {
+            Some(day) => bail!("no solution for {year} day {day}"),
+            None => bail!("no solutions for {year}"),
+        }
+    }
+    Ok(solutions)
+}
+
+fn run(year: u32, input_dir: Option<&Path>, args: RunArgs) -> anyhow::Result<()> {
+    if args.all {
+        let provider = provider(input_dir)?;
+        let name = args.input_name.as_deref();
+        let jobs = args.jobs.unwrap_or(0);
+        let report = runner::run_all(year, &provider, name, args.part, jobs)?;
+        match args.format.unwrap_or(Format::Text) {
+            Format::Text => print!("{}", report.render()),
+            Format::Json => println!("{}", report.to_json()?),
+        }
+        let failures = report.failures();
+        if failures > 0 {
+            bail!("{failures} parts failed or could not be solved");
+        }
+        return Ok(());
+    }
+
+    let day = args.day.unwrap();
+    let solution = select(year, Some(day))?[0];
+    let name = args.input_name.as_deref();
+    let input = read_input(year, input_dir, day, args.input.as_deref(), name)?;
+    let parsed = solution.parse(&input)?;
+    let answers = match (&args.input, args.params.is_empty()) {
+        (None, true) => Answers::load(year, day)?,
+        _ => Answers::default(),
+    };
+    for part in parts(solution, args.part)? {
+        let answer = solution.solve(parsed.as_ref(), part, &args.params)?;
+        println!("{answer}");
+        warn_if_wrong(&answers, day, name, part, &answer);
+    }
+    Ok(())
+}
+
+fn verify(year: u32, input_dir: Option<&Path>, args: VerifyArgs) -> anyhow::Result<()> {
+    let provider = provider(input_dir)?;
+    let name = args.input_name.as_deref();
+    let solutions = select(year, args.day)?;
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for solution in solutions {
+        let day = solution.day();
+        if !provider.has_input(year, day, name) {
+            let path = provider.inputs().path(year, day, name);
+            println!("Day {day:02}: no input at {}", path.display());
+            unknown += solution.parts().len();
+            continue;
+        }
+        let parsed = solution.parse(&provider.input(year, day, name)?)?;
+        let answers = Answers::load(year, day)?;
+        for &part in solution.parts() {
+            let answer = solution.solve(parsed.as_ref(), part, &[])?;
+            let verdict = answers.check(name, part, &answer);
+            println!("Day {day:02} part {part}: {answer} {verdict}");
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Unknown => unknown += 1,
+                _ => failed += 1,
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+    if failed > 0 {
+        bail!("{failed} answers failed verification");
+    }
+    Ok(())
+}
+
+fn bench(year: u32, input_dir: Option<&Path>, args: BenchArgs) -> anyhow::Result<()> {
+    let provider = provider(input_dir)?;
+    let solutions = select(year, args.day)?;
+    let budget = Budget {
+        time: Duration::from_secs_f64(args.seconds),
+        max_samples: args.max_samples,
+    };
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let threshold = args.threshold / 100.;
+
+    let mut timings = Vec::new();
+    let mut errors = 0;
+    for solution in solutions {
+        let day = solution.day();
+        let input = match args.generate {
+            Some(size) => solution.generate(args.seed, size),
+            None if !provider.has_input(year, day, None) => {
+                let path = provider.inputs().path(year, day, None);
+                eprintln!("Day {day:02}: no input at {}", path.display());
+                continue;
+            }
+            None => provider.input(year, day, None),
+        };
+        // One day failing shouldn't lose the timings of the rest.
+        match input
+            .and_then(|input| bench::bench_day(year, solution, &input, &args.params, &budget))
+        {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(err) => {
+                eprintln!("Day {day:02}: {err:#}");
+                errors += 1;
+            }
+        }
+    }
+
+    let compare = baseline.as_ref().map(|baseline| (baseline, threshold));
+    print!("{}", bench::render(&timings, compare, args.markdown));
+    if let Some(path) = &args.save_baseline {
+        Baseline::from_timings(&timings).save(path)?;
+    }
+    if let Some(baseline) = &baseline {
+        let regressions = timings
+            .iter()
+            .filter(|timing| {
+                matches!(
+                    baseline.compare(timing, threshold),
+                    bench::Change::Regression(_)
+                )
+            })
+            .count();
+        if regressions > 0 {
+            bail!(
+                "{regressions} stages regressed by more than {}%",
+                args.threshold
+            );
+        }
+    }
+    if errors > 0 {
+        bail!("{errors} of the days could not be benchmarked");
+    }
+    Ok(())
+}
+
+fn new_day(year: u32, input_dir: Option<&Path>, args: NewArgs) -> anyhow::Result<()> {
+    let read = |path: &Option<PathBuf>| {
+        path.as_ref()
+            .map(|path| {
+                fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+            })
+            .transpose()
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let input_dir = input_dir.map_or_else(|| root.join("input"), Path::to_path_buf);
+    let mut example1 = read(&args.example1)?;
+    let provider = Provider::from_env(Inputs::new(&input_dir));
+    if example1.is_none() && provider.can_fetch() {
+        match provider.examples(year, args.day) {
+            Ok(examples) => example1 = examples.into_iter().next(),
+            Err(err) => eprintln!("warning: no examples fetched: {err:#}"),
+        }
+    }
+    let examples = [
+        Example { text: example1, answer: args.answer1 },
+        Example { text: read(&args.example2)?, answer: args.answer2 },
+    ];
+    for path in Scaffold::new(root).create(year, args.day, &input_dir, &examples)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn visualise(year: u32, input_dir: Option<&Path>, args: VizArgs) -> anyhow::Result<()> {
+    let solution = select(year, Some(args.day))?[0];
+    let input = read_input(year, input_dir, args.day, args.input.as_deref(), None)?;
+    let parsed = solution.parse(&input)?;
+    let frames = solution.visualise(parsed.as_ref(), &args.params)?;
+    let output = args.output.unwrap_or_else(|| {
+        let extension = if frames.len() > 1 { "gif" } else { "png" };
+        PathBuf::from(format!("day{:02}.{extension}", args.day))
+    });
+    let delay = Duration::from_millis(args.delay_ms);
+    for path in viz::save(&frames, &output, args.scale, delay)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn dot(year: u32, input_dir: Option<&Path>, args: DotArgs) -> anyhow::Result<()> {
+    let solution = select(year, Some(args.day))?[0];
+    let input = read_input(year, input_dir, args.day, args.input.as_deref(), None)?;
+    let parsed = solution.parse(&input)?;
+    print!("{}", solution.dot(parsed.as_ref(), &[])?);
+    Ok(())
+}
+
+fn generate(year: u32, args: GenArgs) -> anyhow::Result<()> {
+    let solution = select(year, Some(args.day))?[0];
+    print!("{}", solution.generate(args.seed, args.size)?);
+    Ok(())
+}
+
+fn report(year: u32, input_dir: Option<&Path>, args: ReportArgs) -> anyhow::Result<()> {
+    let solution = select(year, Some(args.day))?[0];
+    let name = args.input_name.as_deref();
+    let input = read_input(year, input_dir, args.day, args.input.as_deref(), name)?;
+    let parsed = solution.parse(&input)?;
+    println!("{}", solution.report(parsed.as_ref(), &args.params)?);
+    Ok(())
+}
+
+/// Send log output to stderr, so it never mixes with answers.
+fn init_tracing(verbose: u8) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| match verbose {
+        0 => EnvFilter::new("warn"),
+        1 => EnvFilter::new("warn,advent_of_code=debug"),
+        _ => EnvFilter::new("warn,advent_of_code=trace"),
+    });
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+    let year = cli.year.unwrap_or_else(runner::latest_year);
+    let input_dir = cli.input_dir.as_deref();
+    match cli.command {
+        Command::Run(args) => run(year, input_dir, args),
+        Command::Verify(args) => verify(year, input_dir, args),
+        Command::Bench(args) => bench(year, input_dir, args),
+        Command::New(args) => new_day(year, input_dir, args),
+        Command::Viz(args) => visualise(year, input_dir, args),
+        Command::Dot(args) => dot(year, input_dir, args),
+        Command::Gen(args) => generate(year, args),
+        Command::Report(args) => report(year, input_dir, args),
+    }
+}
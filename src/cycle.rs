@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use itertools::Itertools;
+
+use crate::util;
+
+/// Where an eventually periodic sequence `x0, step(x0), step(step(x0)), ...` repeats: after the
+/// first `start` states it goes round the next `period` states forever.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The index, in `0..start + period`, of the first state equal to state `n`.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Find the cycle with Brent's algorithm, which keeps only two states at a time and needs no
+/// `Hash`, at the cost of stepping some states more than once.
+pub fn brent<T: Clone + Eq>(x0: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // Find the period: the hare runs ahead in powers of two until it meets the tortoise.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(&x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Then the start: two states `period` apart first meet at the start of the cycle.
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Every state of a sequence up to the end of its first cycle, found by remembering each state
+/// seen. Each state is stepped only once, which suits expensive steps.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    pub states: Vec<T>,
+    pub cycle: Cycle,
+}
+
+impl<T: Clone + Hash + Eq> History<T> {
+    pub fn find(x0: T, mut step: impl FnMut(&T) -> T) -> Self {
+        let mut seen = HashMap::new();
+        let mut states = Vec::new();
+        let mut state = x0;
+        loop {
+            if let Some(&start) = seen.get(&state) {
+                let cycle = Cycle { start, period: states.len() - start };
+                return History { states, cycle };
+            }
+            seen.insert(state.clone(), states.len());
+            let next = step(&state);
+            states.push(state);
+            state = next;
+        }
+    }
+}
+
+impl<T> History<T> {
+    /// State `n` of the sequence, however far past the first cycle.
+    pub fn nth(&self, n: usize) -> &T {
+        &self.states[self.cycle.index(n)]
+    }
+
+    /// The indices of the states in the first cycle that match `pred`.
+    pub fn positions(&self, pred: impl Fn(&T) -> bool) -> Vec<usize> {
+        self.states.iter().positions(pred).collect()
+    }
+}
+
+/// State `n` of `x0, step(x0), ...`, extrapolated from its first cycle.
+pub fn nth_state<T: Clone + Hash + Eq>(x0: T, step: impl FnMut(&T) -> T, n: usize) -> T {
+    History::find(x0, step).nth(n).clone()
+}
+
+/// The first `n >= from` at which every sequence is at one of its hits, given each sequence's
+/// cycle and the sorted indices, in `0..start + period`, of its hits. `None` if the hits never
+/// line up or `n` overflows a `u64`.
+///
+/// Every combination of hits within the sequences' cycles is tried, so this suits sequences with
+/// only a few hits per cycle.
+pub fn first_common(sequences: &[(Cycle, Vec<usize>)], from: usize) -> Option<u64> {
+    let is_hit = |n: usize| {
+        sequences
+            .iter()
+            .all(|(cycle, hits)| hits.binary_search(&cycle.index(n)).is_ok())
+    };
+
+    // Before every sequence is in its cycle, check each step in turn.
+    let tail = sequences
+        .iter()
+        .map(|(cycle, _)| cycle.start)
+        .max()
+        .unwrap_or(0)
+        .max(from);
+    if let Some(n) = (from..tail).find(|&n| is_hit(n)) {
+        return Some(n as u64);
+    }
+
+    // After that, each hit in a cycle recurs every period.
+    let tail = tail as i128;
+    sequences
+        .iter()
+        .map(|(cycle, hits)| {
+            hits.iter()
+                .filter(|&&hit| hit >= cycle.start)
+                .map(|&hit| (hit as i128, cycle.period as i128))
+                .collect_vec()
+        })
+        .multi_cartesian_product()
+        .filter_map(|pairs| {
+            let (offset, period) = util::crt(pairs)?;
+            let laps = (tail - offset).max(0).checked_add(period - 1)? / period;
+            offset.checked_add(laps.checked_mul(period)?)
+        })
+        .min()
+        .and_then(|n| u64::try_from(n).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{brent, first_common, nth_state, Cycle, History};
+
+    #[test]
+    fn test_cycle() {
+        // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+        let step = |&x: &u32| if x == 5 { 3 } else { x + 1 };
+        let cycle = Cycle { start: 3, period: 3 };
+        assert_eq!(brent(0, step), cycle);
+        let history = History::find(0, step);
+        assert_eq!(history.cycle, cycle);
+        assert_eq!(history.states, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(*history.nth(2), 2);
+        assert_eq!(*history.nth(1_000_000_000), 4);
+        assert_eq!(nth_state(0, step, 9), 3);
+        assert_eq!(brent(7, |&x| x), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn test_first_common() {
+        let a = (Cycle { start: 1, period: 2 }, vec![2]);
+        let b = (Cycle { start: 1, period: 6 }, vec![3, 6]);
+        assert_eq!(first_common(&[a.clone(), b.clone()], 1), Some(6));
+        assert_eq!(first_common(&[a.clone(), b], 7), Some(12));
+        // Hits before the cycle count only once.
+        let c = (Cycle { start: 4, period: 2 }, vec![2, 5]);
+        assert_eq!(first_common(&[a.clone(), c.clone()], 0), Some(2));
+        assert_eq!(first_common(&[a.clone(), c], 3), None);
+        let odd = (Cycle { start: 0, period: 2 }, vec![1]);
+        assert_eq!(first_common(&[a, odd], 0), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_brent(next in prop::collection::vec(0..20usize, 20), x0 in 0..20usize) {
+            let step = |&x: &usize| next[x];
+            let history = History::find(x0, step);
+            prop_assert_eq!(brent(x0, step), history.cycle);
+            let mut x = x0;
+            for n in 0..50 {
+                prop_assert_eq!(*history.nth(n), x);
+                x = step(&x);
+            }
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+
+use crate::input::Inputs;
+
+/// Environment variable naming a directory that mirrors the site, to fetch from instead of it.
+pub const MIRROR_VAR: &str = "AOC_MIRROR";
+
+/// Environment variable holding the session cookie used to fetch inputs over HTTP.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the site fetched from over HTTP.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Somewhere puzzle pages and inputs can be fetched from, by their path on the site, e.g.
+/// `2023/day/5` for a puzzle and `2023/day/5/input` for its input.
+pub trait Backend: Send + Sync {
+    fn get(&self, path: &str) -> Result<String>;
+}
+
+/// A copy of the site on disk, with each path a file, or a directory holding `index.html`.
+pub struct Mirror {
+    root: PathBuf,
+}
+
+impl Mirror {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Mirror { root: root.into() }
+    }
+}
+
+impl Backend for Mirror {
+    fn get(&self, path: &str) -> Result<String> {
+        let mut file = self.root.join(path);
+        if file.is_dir() {
+            file = file.join("index.html");
+        }
+        fs::read_to_string(&file).with_context(|| format!("reading {}", file.display()))
+    }
+}
+
+/// The site itself, or anything serving the same paths, logged in with a session cookie.
+pub struct Http {
+    base_url: String,
+    session: String,
+}
+
+impl Http {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Http {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+}
+
+impl Backend for Http {
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}/{path}", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "github.com/swidoff/aoc-2023")
+            .call()
+            .with_context(|| format!("fetching {url}"))?;
+        response
+            .into_string()
+            .with_context(|| format!("reading {url}"))
+    }
+}
+
+/// Reads inputs from a directory, fetching any that are missing and caching them there.
+pub struct Provider {
+    inputs: Inputs,
+    backend: Option<Box<dyn Backend>>,
+}
+
+impl Provider {
+    pub fn new(inputs: Inputs, backend: Option<Box<dyn Backend>>) -> Self {
+        Provider { inputs, backend }
+    }
+
+    /// Fetch from `AOC_MIRROR` if it is set, otherwise over HTTP if `AOC_SESSION` is, otherwise
+    /// not at all.
+    pub fn from_env(inputs: Inputs) -> Self {
+        let backend: Option<Box<dyn Backend>> = if let Some(root) = env::var_os(MIRROR_VAR) {
+            Some(Box::new(Mirror::new(root)))
+        } else if let Ok(session) = env::var(SESSION_VAR) {
+            let base_url = env::var(BASE_URL_VAR);
+            let base_url = base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+            Some(Box::new(Http::new(base_url, &session)))
+        } else {
+            None
+        };
+        Provider::new(inputs, backend)
+    }
+
+    pub fn inputs(&self) -> &Inputs {
+        &self.inputs
+    }
+
+    pub fn can_fetch(&self) -> bool {
+        self.backend.is_some()
+    }
+
+    /// Whether the input is on disk or can be fetched. Only default inputs are fetched.
+    pub fn has_input(&self, year: u32, day: u32, name: Option<&str>) -> bool {
+        !self.is_missing(year, day, name) || (name.is_none() && self.can_fetch())
+    }
+
+    /// Empty files, as left by `aoc new`, count as missing.
+    fn is_missing(&self, year: u32, day: u32, name: Option<&str>) -> bool {
+        let path = self.inputs.path(year, day, name);
+        fs::metadata(path).map_or(true, |meta| !meta.is_file() || meta.len() == 0)
+    }
+
+    /// Read an input, first fetching and caching it if it is the day's default input and missing.
+    pub fn input(&self, year: u32, day: u32, name: Option<&str>) -> Result<String> {
+        match &self.backend {
+            Some(backend) if name.is_none() && self.is_missing(year, day, None) => {
+                let input = backend.get(&format!("{year}/day/{day}/input"))?;
+                cache(&self.inputs.path(year, day, None), &input)?;
+                Ok(input)
+            }
+            _ => self.inputs.read(year, day, name),
+        }
+    }
+
+    /// The examples on a day's puzzle page, which is cached next to the inputs as `dayNN.html`.
+    pub fn examples(&self, year: u32, day: u32) -> Result<Vec<String>> {
+        let path = self.inputs.year_dir(year).join(format!("day{day:02}.html"));
+        let page = if path.is_file() {
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?
+        } else if let Some(backend) = &self.backend {
+            let page = backend.get(&format!("{year}/day/{day}"))?;
+            cache(&path, &page)?;
+            page
+        } else {
+            bail!("no puzzle page for {year} day {day}: set {MIRROR_VAR} or {SESSION_VAR}");
+        };
+        Ok(examples(&page))
+    }
+}
+
+fn cache(path: &Path, text: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    fs::write(path, text).with_context(|| format!("writing {}", path.display()))
+}
+
+/// The contents of each `<pre><code>` block on a puzzle page, with markup removed.
+pub fn examples(page: &str) -> Vec<String> {
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    block
+        .captures_iter(page)
+        .map(|c| {
+            tag.replace_all(&c[1], "")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&amp;", "&")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::{examples, Backend, Http, Mirror, Provider};
+    use crate::input::Inputs;
+
+    const PAGE: &str = "<article><p>For example:</p>
+<pre><code>1abc2
+<em>a&lt;b</em> &amp; c
+</code></pre><p>Sum: <code>142</code>.</p></article>";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), ["1abc2\na<b & c\n"]);
+        assert!(examples("<p>no examples</p>").is_empty());
+    }
+
+    #[test]
+    fn test_provider() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let mirror = dir.join("mirror");
+        fs::create_dir_all(mirror.join("2023/day/5")).unwrap();
+        fs::write(mirror.join("2023/day/5/input"), "seeds: 1\n").unwrap();
+        fs::write(mirror.join("2023/day/5/index.html"), PAGE).unwrap();
+        let inputs = dir.join("input");
+        fs::create_dir_all(inputs.join("2023")).unwrap();
+        fs::write(inputs.join("2023/day06.txt"), "").unwrap();
+
+        let provider = Provider::new(Inputs::new(&inputs), Some(Box::new(Mirror::new(&mirror))));
+        assert_eq!(provider.input(2023, 5, None).unwrap(), "seeds: 1\n");
+        let cached = fs::read_to_string(inputs.join("2023/day05.txt")).unwrap();
+        assert_eq!(cached, "seeds: 1\n");
+        assert_eq!(provider.examples(2023, 5).unwrap().len(), 1);
+        assert!(inputs.join("2023/day05.html").is_file());
+        // Empty inputs are fetched again, named ones never are.
+        assert!(provider.input(2023, 6, None).is_err());
+        assert!(!provider.has_input(2023, 5, Some("alice")));
+
+        let offline = Provider::new(Inputs::new(&inputs), None);
+        assert_eq!(offline.input(2023, 6, None).unwrap(), "");
+        assert!(!offline.has_input(2023, 6, None));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push(line);
+            }
+            let body = "0 3 6 9\n";
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        let http = Http::new(&format!("http://{addr}/"), "abc123\n");
+        assert_eq!(http.get("2023/day/9/input").unwrap(), "0 3 6 9\n");
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2023/day/9/input HTTP/1.1");
+        let cookie = |l: &String| l.eq_ignore_ascii_case("cookie: session=abc123");
+        assert!(request.iter().any(cookie), "{request:?}");
+    }
+}
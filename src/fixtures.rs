@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::answers::Value;
+use crate::solution::{Answer, AnySolution};
+
+/// Where the `YEAR/dayNN/` example directories live.
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+/// The directory holding a day's examples: `NAME.txt` inputs, each with a `NAME.toml` of the
+/// answers it should give.
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    fixtures_dir()
+        .join(year.to_string())
+        .join(format!("day{day:02}"))
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureFile {
+    input: Option<String>,
+    part1: Option<Value>,
+    part2: Option<Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+/// One example: an input, the answers it should give and the parameters to solve it with.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+    pub params: Vec<(String, String)>,
+}
+
+impl Fixture {
+    /// Parse `NAME.toml`. Its input is `NAME.txt` unless it names another file in the directory,
+    /// so several fixtures can share one input with different parameters.
+    pub fn parse(dir: &Path, name: &str, text: &str) -> Result<Self> {
+        let file: FixtureFile = toml::from_str(text)?;
+        let input = dir.join(file.input.unwrap_or_else(|| format!("{name}.txt")));
+        let params = file
+            .params
+            .into_iter()
+            .map(|(key, value)| match value {
+                toml::Value::String(s) => (key, s),
+                value => (key, value.to_string()),
+            })
+            .collect();
+        Ok(Fixture {
+            name: name.to_string(),
+            input: read(&input)?,
+            answers: [file.part1.map(Answer::from), file.part2.map(Answer::from)],
+            params,
+        })
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+/// Every fixture for the day, by name. A day with no directory has none.
+pub fn load(year: u32, day: u32) -> Result<Vec<Fixture>> {
+    let dir = day_dir(year, day);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    names
+        .iter()
+        .map(|name| {
+            let path = dir.join(format!("{name}.toml"));
+            Fixture::parse(&dir, name, &read(&path)?)
+                .with_context(|| format!("parsing {}", path.display()))
+        })
+        .collect()
+}
+
+/// The text of one of the day's example inputs, for tests of more than the answers.
+pub fn input(year: u32, day: u32, name: &str) -> Result<String> {
+    read(&day_dir(year, day).join(format!("{name}.txt")))
+}
+
+/// Solve every fixture that has an answer for `part`, and fail listing each one that is wrong.
+pub fn check(year: u32, solution: &dyn AnySolution, part: u32) -> Result<()> {
+    let mut failures = Vec::new();
+    for fixture in load(year, solution.day())? {
+        let Some(expected) = &fixture.answers[part as usize - 1] else {
+            continue;
+        };
+        let answer = solution
+            .parse(&fixture.input)
+            .and_then(|input| solution.solve(&*input, part, &fixture.params));
+        match answer {
+            Ok(answer) if answer == *expected => {}
+            Ok(answer) => failures.push(format!(
+                "{}: expected {expected}, got {answer}",
+                fixture.name
+            )),
+            Err(e) => failures.push(format!("{}: {e:#}", fixture.name)),
+        }
+    }
+    if !failures.is_empty() {
+        bail!(
+            "{year} day {} part {part} examples failed:\n{}",
+            solution.day(),
+            failures.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// Tests that check a day's solution against its fixtures, one per part.
+#[cfg(test)]
+macro_rules! example_tests {
+    ($year:literal, $day:ty) => {
+        #[test]
+        fn test_part1_examples() {
+            let solution = crate::solution::Erased::<$day>::new();
+            crate::fixtures::check($year, &solution, 1).unwrap();
+        }
+
+        #[test]
+        fn test_part2_examples() {
+            let solution = crate::solution::Erased::<$day>::new();
+            crate::fixtures::check($year, &solution, 2).unwrap();
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use example_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::{day_dir, load, Fixture};
+    use crate::solution::Answer;
+
+    #[test]
+    fn test_parse() {
+        let dir = day_dir(2023, 11);
+        let fixture = Fixture::parse(
+            &dir,
+            "wide",
+            "input = \"example1.txt\"\npart2 = 8410\n\n[params]\nmultiplier = 100\n",
+        )
+        .unwrap();
+        assert!(fixture.input.starts_with("...#......\n"));
+        assert_eq!(fixture.answers, [None, Some(Answer::from(8410))]);
+        assert_eq!(
+            fixture.params,
+            [("multiplier".to_string(), "100".to_string())]
+        );
+        assert!(Fixture::parse(&dir, "typo", "part3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_fixture_files() {
+        for day in 1..=25 {
+            let fixtures = load(2023, day).unwrap();
+            assert!(!fixtures.is_empty(), "day {day}");
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The random number generator every day's generator draws from, seeded so an input can be made
+/// again from its seed and size.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// `count` distinct names of `len` lowercase letters, avoiding any in `reserved`.
+pub fn names(rng: &mut StdRng, count: usize, len: usize, reserved: &[&str]) -> Vec<String> {
+    let mut seen: HashSet<String> = reserved.iter().map(|s| s.to_string()).collect();
+    let mut res = Vec::with_capacity(count);
+    while res.len() < count {
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if seen.insert(name.clone()) {
+            res.push(name);
+        }
+    }
+    res
+}
+
+/// A proptest strategy for one day's inputs, with sizes drawn from `sizes`.
+#[cfg(test)]
+pub(crate) fn strategy(
+    generate: fn(&mut StdRng, usize) -> String,
+    sizes: std::ops::Range<usize>,
+) -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::prelude::*;
+    (any::<u64>(), sizes).prop_map(move |(seed, size)| generate(&mut rng(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{names, rng};
+    use crate::runner;
+
+    #[test]
+    fn test_names() {
+        let names = names(&mut rng(1), 50, 2, &["in"]);
+        assert_eq!(names.len(), 50);
+        assert!(names.iter().all(|n| n.len() == 2 && n != "in"));
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 50);
+    }
+
+    #[test]
+    fn test_generators() {
+        for solution in runner::solutions(2023) {
+            for seed in 0..3 {
+                let day = solution.day();
+                let input = solution.generate(seed, 5).unwrap();
+                assert_eq!(input, solution.generate(seed, 5).unwrap(), "day {day}");
+                let parsed = solution
+                    .parse(&input)
+                    .unwrap_or_else(|e| panic!("day {day} seed {seed}: {e}\n{input}"));
+                // Day 21's default step count only lines up with the real garden, 131 wide; the
+                // generated one is 11 wide with the start 5 from the edge.
+                let params = match day {
+                    21 => vec![("tiled_steps".to_string(), (5 + 4 * 11).to_string())],
+                    _ => Vec::new(),
+                };
+                for &part in solution.parts() {
+                    if let Err(e) = solution.solve(parsed.as_ref(), part, &params) {
+                        panic!("day {day} part {part} seed {seed}: {e}\n{input}");
+                    }
+                }
+            }
+        }
+    }
+}
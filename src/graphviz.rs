@@ -0,0 +1,116 @@
+use std::fmt;
+
+/// A Graphviz graph, built a node and an edge at a time and printed in the DOT language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dot {
+    directed: bool,
+    statements: Vec<String>,
+}
+
+/// Something that can be drawn as a graph, e.g. with `dot -Tsvg` or, for large graphs, `neato`.
+pub trait ToDot {
+    fn to_dot(&self) -> Dot;
+}
+
+impl Dot {
+    /// A graph whose edges have a direction, drawn as arrows.
+    pub fn digraph() -> Self {
+        Dot { directed: true, statements: Vec::new() }
+    }
+
+    /// A graph whose edges have no direction.
+    pub fn graph() -> Self {
+        Dot { directed: false, statements: Vec::new() }
+    }
+
+    /// Set an attribute of the whole graph, e.g. `rankdir=LR`.
+    pub fn attr(&mut self, key: &str, value: &str) -> &mut Self {
+        self.statements.push(format!("{key}={}", quote(value)));
+        self
+    }
+
+    /// Add a node with attributes such as `shape` or `label`. Nodes only named by edges need not
+    /// be added.
+    pub fn node(&mut self, id: &str, attrs: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("{}{}", quote(id), attr_list(attrs)));
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attrs: &[(&str, &str)]) -> &mut Self {
+        let op = if self.directed { "->" } else { "--" };
+        let (from, to) = (quote(from), quote(to));
+        self.statements
+            .push(format!("{from} {op} {to}{}", attr_list(attrs)));
+        self
+    }
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.directed { "digraph" } else { "graph" };
+        writeln!(f, "{kind} G {{")?;
+        for statement in &self.statements {
+            writeln!(f, "    {statement}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// An ID as is if DOT allows it bare, otherwise quoted.
+fn quote(id: &str) -> String {
+    let bare = id.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let number = !id.is_empty() && id.chars().all(|c| c.is_ascii_digit());
+    if bare || number {
+        id.to_string()
+    } else {
+        format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn attr_list(attrs: &[(&str, &str)]) -> String {
+    if attrs.is_empty() {
+        return String::new();
+    }
+    let attrs = attrs
+        .iter()
+        .map(|(key, value)| format!("{key}={}", quote(value)))
+        .collect::<Vec<_>>();
+    format!(" [{}]", attrs.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quote, Dot};
+
+    #[test]
+    fn test_dot() {
+        let mut dot = Dot::digraph();
+        dot.attr("rankdir", "LR")
+            .node("in", &[("shape", "box")])
+            .edge("in", "a<2006", &[("label", "x \"y\"")]);
+        assert_eq!(
+            dot.to_string(),
+            "digraph G {
+    rankdir=LR
+    in [shape=box]
+    in -> \"a<2006\" [label=\"x \\\"y\\\"\"]
+}
+"
+        );
+
+        let mut dot = Dot::graph();
+        dot.edge("jvq", "nvr", &[]);
+        assert_eq!(dot.to_string(), "graph G {\n    jvq -- nvr\n}\n");
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("pkb_ssm"), "pkb_ssm");
+        assert_eq!(quote("42"), "42");
+        assert_eq!(quote("4a"), "\"4a\"");
+        assert_eq!(quote(""), "\"\"");
+        assert_eq!(quote("1,2"), "\"1,2\"");
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+/// A position on a grid. Signed, so a step off the edge, or out into the infinitely tiled plane
+/// around the grid, is still a position that can be looked up or checked.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Pos {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Pos { row, col }
+    }
+
+    pub fn step(self, dir: Dir) -> Pos {
+        self.steps(dir, 1)
+    }
+
+    pub fn steps(self, dir: Dir, n: i64) -> Pos {
+        let (row, col) = dir.delta();
+        Pos::new(self.row + row * n, self.col + col * n)
+    }
+
+    pub fn manhattan(self, other: Pos) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+}
+
+/// A compass direction, with north pointing up towards row 0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    /// Clockwise from north.
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    /// The (row, col) offset of one step in this direction.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Dir::North => (-1, 0),
+            Dir::East => (0, 1),
+            Dir::South => (1, 0),
+            Dir::West => (0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::North | Dir::South)
+    }
+}
+
+/// A rectangular grid stored row by row in one `Vec`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows, which must all be the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same width"
+        );
+        let cells = rows.into_iter().flatten().collect();
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.height as i64).contains(&pos.row) && (0..self.width as i64).contains(&pos.col)
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.row as usize * self.width + pos.col as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Look up a position on the infinite plane made by tiling copies of the grid.
+    pub fn get_wrapping(&self, pos: Pos) -> &T {
+        let row = pos.row.rem_euclid(self.height as i64);
+        let col = pos.col.rem_euclid(self.width as i64);
+        &self[Pos::new(row, col)]
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = Pos> {
+        let width = self.width.max(1);
+        (0..self.cells.len()).map(move |i| Pos::new((i / width) as i64, (i % width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    /// The neighbours north, east, south and west of a position that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .map(move |dir| pos.step(dir))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// The neighbours of a position on the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        (-1..=1)
+            .flat_map(move |row| (-1..=1).map(move |col| Pos::new(pos.row + row, pos.col + col)))
+            .filter(move |&p| p != pos && self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        let cells = self.cells.iter().map(f).collect();
+        Grid { width: self.width, height: self.height, cells }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|col| self.column(col).rev().cloned())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a rectangular grid of characters, rejecting any character not in `allowed`.
+    pub fn parse(day: u32, input: &str, allowed: &str) -> Result<Self, ParseError> {
+        Ok(Grid::from_rows(parse::grid(day, input, allowed)?))
+    }
+
+    /// An error pointing at the cell at `pos`, for problems found after parsing. Rows and columns
+    /// are lines and columns of the input the grid was parsed from.
+    pub fn error(&self, day: u32, pos: Pos, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: (pos.row + 1).max(1) as usize,
+            column: (pos.col + 1).max(1) as usize,
+            token: self.get(pos).map_or(String::new(), |c| c.to_string()),
+            message: message.into(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(t) => t,
+            None => panic!("{pos:?} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(t) => t,
+            None => panic!("{pos:?} is outside the {width}x{height} grid"),
+        }
+    }
+}
+
+/// One line per row, each ending in a newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for t in row {
+                write!(f, "{t}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dir, Grid, Pos};
+
+    const EXAMPLE: &str = "ab.
+#cd
+";
+
+    #[test]
+    fn test_lookup() {
+        let grid = Grid::parse(0, EXAMPLE, "abcd.#").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 2)], 'd');
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+        assert_eq!(*grid.get_wrapping(Pos::new(-1, -1)), 'd');
+        assert_eq!(*grid.get_wrapping(Pos::new(4, 7)), 'b');
+        assert_eq!(grid.find(|&c| c == '#'), Some(Pos::new(1, 0)));
+        assert_eq!(grid.column(1).collect::<String>(), "bc");
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse(0, EXAMPLE, "abcd.#").unwrap();
+        assert_eq!(grid.transpose().to_string(), "a#\nbc\n.d\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "#a\ncb\nd.\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), ".d\nbc\na#\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_dirs() {
+        assert_eq!(Pos::new(1, 1).step(Dir::North), Pos::new(0, 1));
+        assert_eq!(Pos::new(1, 1).steps(Dir::West, 3), Pos::new(1, -2));
+        assert_eq!(Dir::North.turn_left(), Dir::West);
+        assert_eq!(Dir::West.turn_right(), Dir::North);
+        assert_eq!(Dir::East.reverse(), Dir::West);
+    }
+}
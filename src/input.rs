@@ -0,0 +1,145 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// Environment variable naming the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The year of the inputs in a flat input directory.
+pub const FLAT_YEAR: u32 = 2023;
+
+/// The directory holding the puzzle inputs, one subdirectory per year, named `dayNN.txt`, or
+/// `dayNN.<name>.txt` when there is more than one input for a day (e.g. `2023/day17.alice.txt`
+/// and `2023/day17.bob.txt`). A directory without a subdirectory for `FLAT_YEAR` is read flat,
+/// as the inputs were kept before there was more than one year.
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Inputs { dir: dir.into() }
+    }
+
+    /// Use the given directory if there is one, then `AOC_INPUT_DIR`, then the `input` directory
+    /// of the first crate root above the working directory, then this crate's own.
+    pub fn locate(dir: Option<&Path>) -> Result<Self> {
+        if let Some(dir) = dir {
+            return Ok(Inputs::new(dir));
+        }
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+            return Ok(Inputs::new(dir));
+        }
+        let cwd = env::current_dir().context("reading the working directory")?;
+        let crate_roots = cwd
+            .ancestors()
+            .filter(|dir| dir.join("Cargo.toml").is_file());
+        let candidates = crate_roots.chain([Path::new(env!("CARGO_MANIFEST_DIR"))]);
+        for root in candidates {
+            let dir = root.join("input");
+            if dir.is_dir() {
+                return Ok(Inputs::new(dir));
+            }
+        }
+        bail!("no input directory found; set {INPUT_DIR_VAR} or pass --input-dir")
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Where inputs for the year live. Only `FLAT_YEAR` falls back to the directory itself.
+    pub fn year_dir(&self, year: u32) -> PathBuf {
+        let dir = self.dir.join(year.to_string());
+        if dir.is_dir() || year != FLAT_YEAR {
+            dir
+        } else {
+            self.dir.clone()
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32, name: Option<&str>) -> PathBuf {
+        let dir = self.year_dir(year);
+        match name {
+            Some(name) => dir.join(format!("day{day:02}.{name}.txt")),
+            None => dir.join(format!("day{day:02}.txt")),
+        }
+    }
+
+    pub fn read(&self, year: u32, day: u32, name: Option<&str>) -> Result<String> {
+        let dir = self.year_dir(year);
+        if !dir.is_dir() {
+            bail!(
+                "no input for {year}: {} does not exist (set {INPUT_DIR_VAR} or pass --input-dir \
+                 to read inputs from elsewhere)",
+                dir.display()
+            );
+        }
+        let path = self.path(year, day, name);
+        if !path.is_file() {
+            let which = name
+                .map(|name| format!(" named `{name}`"))
+                .unwrap_or_default();
+            bail!(
+                "no input for {year} day {day}{which}: {} does not exist (set {INPUT_DIR_VAR} or pass \
+                 --input-dir to read inputs from elsewhere)",
+                path.display()
+            );
+        }
+        fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+    }
+}
+
+/// Read a day's default input from wherever `Inputs::locate` finds it.
+pub fn read_input(year: u32, day: u32) -> Result<String> {
+    Inputs::locate(None)?.read(year, day, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::Inputs;
+
+    #[test]
+    fn test_paths() {
+        let inputs = Inputs::new("inputs");
+        assert_eq!(inputs.path(2023, 7, None), Path::new("inputs/day07.txt"));
+        assert_eq!(
+            inputs.path(2023, 17, Some("alice")),
+            Path::new("inputs/day17.alice.txt")
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2023")).unwrap();
+        fs::write(dir.join("2023/day17.bob.txt"), "123\n").unwrap();
+        fs::write(dir.join("day17.bob.txt"), "456\n").unwrap();
+
+        let inputs = Inputs::locate(Some(&dir)).unwrap();
+        assert_eq!(inputs.read(2023, 17, Some("bob")).unwrap(), "123\n");
+        let err = inputs.read(2022, 17, Some("bob")).unwrap_err().to_string();
+        assert!(err.starts_with("no input for 2022: "), "{err}");
+        let err = inputs
+            .read(2023, 17, Some("alice"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with("no input for 2023 day 17 named `alice`"),
+            "{err}"
+        );
+        assert!(err.contains("2023/day17.alice.txt"), "{err}");
+
+        // Without a directory for 2023, its inputs are read from the directory itself.
+        fs::remove_dir_all(dir.join("2023")).unwrap();
+        assert_eq!(inputs.read(2023, 17, Some("bob")).unwrap(), "456\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
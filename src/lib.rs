@@ -1,27 +1,20 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod fetch;
+pub mod fixtures;
+pub mod gen;
+pub mod graphviz;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod table;
+// Only compiled to keep `aoc new`'s template valid; nothing calls it.
+#[allow(dead_code)]
 mod template;
-mod util;
+pub mod util;
+pub mod viz;
+pub mod year2023;
@@ -0,0 +1,249 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where in a puzzle input parsing failed, and the text found there. Lines and columns count
+/// from 1, columns in characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if self.token.is_empty() {
+            write!(f, " (found end of line)")
+        } else {
+            write!(f, " (found `{}`)", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A slice of the puzzle input that remembers where it came from, so that anything that goes
+/// wrong while parsing it can point back at the offending text.
+#[derive(Clone, Copy, Debug)]
+pub struct Token<'a> {
+    day: u32,
+    line: usize,
+    column: usize,
+    text: &'a str,
+}
+
+/// Split the input into line tokens, numbering lines from 1.
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Token<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(i, text)| Token { day, line: i + 1, column: 1, text })
+}
+
+/// The error for an input that stops before the parser has everything it needs.
+pub fn end_of_input(day: u32, input: &str, expected: &str) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        token: String::new(),
+        message: format!("unexpected end of input, expected {expected}"),
+    }
+}
+
+/// Parse a rectangular grid of characters, rejecting any character not in `allowed`.
+pub fn grid(day: u32, input: &str, allowed: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    grid_from_lines(lines(day, input), allowed)
+}
+
+pub fn grid_from_lines<'a>(
+    lines: impl Iterator<Item = Token<'a>>,
+    allowed: &str,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut res: Vec<Vec<char>> = Vec::new();
+    for line in lines {
+        let row = line
+            .chars()
+            .map(|(c, token)| {
+                if allowed.contains(c) {
+                    Ok(c)
+                } else {
+                    Err(token.error(format!("expected one of `{allowed}`")))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(first) = res.first() {
+            if first.len() != row.len() {
+                return Err(line.error(format!("expected {} columns", first.len())));
+            }
+        }
+        res.push(row);
+    }
+    Ok(res)
+}
+
+impl<'a> Token<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.line,
+            column: self.column,
+            token: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Re-anchor a sub-slice of this token's text, working out its column.
+    fn sub(&self, text: &'a str) -> Token<'a> {
+        let offset = text.as_ptr() as usize - self.text.as_ptr() as usize;
+        let column = self.column + self.text[..offset].chars().count();
+        Token { text, column, ..*self }
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text.parse::<T>().map_err(|_| {
+            let name = std::any::type_name::<T>();
+            self.error(format!("expected a value of type {name}"))
+        })
+    }
+
+    pub fn trim(&self) -> Token<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn split_once(&self, sep: &str) -> Result<(Token<'a>, Token<'a>), ParseError> {
+        match self.text.split_once(sep) {
+            Some((lhs, rhs)) => Ok((self.sub(lhs), self.sub(rhs))),
+            None => Err(self.error(format!("expected `{sep}`"))),
+        }
+    }
+
+    pub fn split(self, sep: &'a str) -> impl Iterator<Item = Token<'a>> + 'a {
+        self.text.split(sep).map(move |s| self.sub(s))
+    }
+
+    /// Split on any of the given characters.
+    pub fn split_any(self, seps: &'a [char]) -> impl Iterator<Item = Token<'a>> + 'a {
+        self.text.split(seps).map(move |s| self.sub(s))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Token<'a>> + 'a {
+        self.text.split_whitespace().map(move |s| self.sub(s))
+    }
+
+    /// Split into exactly `N` pieces, reporting an error if there are more or fewer.
+    pub fn split_n<const N: usize>(
+        &self,
+        pieces: impl Iterator<Item = Token<'a>>,
+    ) -> Result<[Token<'a>; N], ParseError> {
+        let pieces: Vec<Token<'a>> = pieces.collect();
+        pieces.try_into().map_err(|pieces: Vec<Token>| {
+            self.error(format!("expected {N} fields, found {}", pieces.len()))
+        })
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("expected `{prefix}`"))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("expected `{suffix}`"))),
+        }
+    }
+
+    /// Slice by byte range, like indexing a `&str`.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> Result<Token<'a>, ParseError> {
+        match self.text.get(range.clone()) {
+            Some(text) => Ok(self.sub(text)),
+            None => Err(self.error(format!("expected at least {} characters", range.end))),
+        }
+    }
+
+    /// Each character along with a token pointing at it.
+    pub fn chars(self) -> impl Iterator<Item = (char, Token<'a>)> + 'a {
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (c, self.sub(&self.text[i..i + c.len_utf8()])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{grid, lines, ParseError};
+
+    #[test]
+    fn test_columns() {
+        let line = lines(7, "first\nGame 12: 3 blue").nth(1).unwrap();
+        let (game, draws) = line.split_once(": ").unwrap();
+        let id = game.strip_prefix("Game ").unwrap();
+        assert_eq!((id.text(), id.line(), id.column()), ("12", 2, 6));
+        let [num, color] = draws.split_n(draws.split(" ")).unwrap();
+        assert_eq!(num.parse::<u32>().unwrap(), 3);
+        assert_eq!(color.column(), 12);
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = lines(2, "Game 1: x blue").next().unwrap();
+        let (_, draws) = line.split_once(": ").unwrap();
+        let err = draws
+            .split_whitespace()
+            .next()
+            .unwrap()
+            .parse::<u32>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                day: 2,
+                line: 1,
+                column: 9,
+                token: "x".to_string(),
+                message: "expected a value of type u32".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "day 02, line 1, column 9: expected a value of type u32 (found `x`)"
+        );
+        assert!(line.split_once(" -> ").is_err());
+        assert!(line.split_n::<3>(line.split(" ")).is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(grid(1, "#.\n.#\n", "#.").unwrap().len(), 2);
+        let err = grid(1, "#.\n.x\n", "#.").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+        let err = grid(1, "#.\n.\n", "#.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
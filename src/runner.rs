@@ -0,0 +1,361 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::answers::{Answers, Verdict};
+use crate::bench::format_duration;
+use crate::fetch::Provider;
+use crate::solution::{Answer, AnySolution};
+use crate::table;
+
+pub const DAYS: std::ops::RangeInclusive<u32> = 1..=25;
+
+/// Every year's dispatch table, oldest first. `aoc new` adds years here.
+#[rustfmt::skip]
+static YEARS: &[(u32, &[&dyn AnySolution])] = &[
+    (2023, crate::year2023::SOLUTIONS),
+];
+
+pub fn years() -> impl Iterator<Item = u32> {
+    YEARS.iter().map(|&(year, _)| year)
+}
+
+/// The year commands default to.
+pub fn latest_year() -> u32 {
+    years().max().unwrap()
+}
+
+pub fn solution(year: u32, day: u32) -> Option<&'static dyn AnySolution> {
+    solutions(year).find(|solution| solution.day() == day)
+}
+
+/// A year's solutions in day order, or none for a year with no solutions.
+pub fn solutions(year: u32) -> impl Iterator<Item = &'static dyn AnySolution> {
+    YEARS
+        .iter()
+        .filter(move |&&(y, _)| y == year)
+        .flat_map(|&(_, solutions)| solutions.iter().copied())
+}
+
+/// How one part fared in `run_all`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved {
+        answer: Answer,
+        verdict: Verdict,
+    },
+    /// The input couldn't be read or parsed, or solving the part failed or panicked.
+    Error(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    /// Time spent solving the part, not counting reading and parsing the input.
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    /// `"pass"`, `"fail"`, `"unknown"` or `"error"`.
+    pub fn result(&self) -> &'static str {
+        match &self.status {
+            Status::Solved { verdict: Verdict::Pass, .. } => "pass",
+            Status::Solved { verdict: Verdict::Unknown, .. } => "unknown",
+            Status::Solved { .. } => "fail",
+            Status::Error(_) => "error",
+        }
+    }
+
+    fn detail(&self) -> String {
+        match &self.status {
+            Status::Solved { verdict, .. } => verdict.to_string(),
+            Status::Error(err) => format!("ERROR: {err}"),
+        }
+    }
+}
+
+/// Every part of a year, solved by `run_all`, in day order.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub year: u32,
+    pub outcomes: Vec<Outcome>,
+    /// Wall-clock time for the whole run.
+    pub elapsed: Duration,
+}
+
+#[derive(Serialize)]
+struct JsonReport {
+    year: u32,
+    seconds: f64,
+    results: Vec<JsonOutcome>,
+}
+
+#[derive(Serialize)]
+struct JsonOutcome {
+    day: u32,
+    part: u32,
+    answer: Option<String>,
+    result: &'static str,
+    detail: String,
+    seconds: f64,
+}
+
+impl Report {
+    /// How many parts failed verification or couldn't be solved.
+    pub fn failures(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome.result(), "fail" | "error"))
+            .count()
+    }
+
+    /// An aligned table with a row per part, then a line of totals.
+    pub fn render(&self) -> String {
+        let header = ["Day", "Part", "Answer", "Time", "Result"];
+        let mut rows = vec![header.map(String::from).to_vec()];
+        for outcome in &self.outcomes {
+            let answer = match &outcome.status {
+                Status::Solved { answer, .. } => answer.to_string(),
+                Status::Error(_) => "-".to_string(),
+            };
+            rows.push(vec![
+                format!("{:02}", outcome.day),
+                outcome.part.to_string(),
+                answer,
+                format_duration(outcome.elapsed),
+                outcome.detail(),
+            ]);
+        }
+        let count = |result| {
+            self.outcomes
+                .iter()
+                .filter(|o| o.result() == result)
+                .count()
+        };
+        format!(
+            "{}{} passed, {} failed, {} unknown, {} errors in {}\n",
+            table::render(&rows, false),
+            count("pass"),
+            count("fail"),
+            count("unknown"),
+            count("error"),
+            format_duration(self.elapsed)
+        )
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let report = JsonReport {
+            year: self.year,
+            seconds: self.elapsed.as_secs_f64(),
+            results: self
+                .outcomes
+                .iter()
+                .map(|outcome| JsonOutcome {
+                    day: outcome.day,
+                    part: outcome.part,
+                    answer: match &outcome.status {
+                        Status::Solved { answer, .. } => Some(answer.to_string()),
+                        Status::Error(_) => None,
+                    },
+                    result: outcome.result(),
+                    detail: outcome.detail(),
+                    seconds: outcome.elapsed.as_secs_f64(),
+                })
+                .collect(),
+        };
+        Ok(serde_json::to_string_pretty(&report)?)
+    }
+}
+
+/// Solve every day of the year, or just the given part of each, with `jobs` days at a time (one
+/// per CPU when `jobs` is 0), checking the answers against the recorded ones. A day that fails
+/// or panics is reported as an error rather than stopping the run.
+pub fn run_all(
+    year: u32,
+    provider: &Provider,
+    name: Option<&str>,
+    part: Option<u32>,
+    jobs: usize,
+) -> Result<Report> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    let solutions = solutions(year).collect::<Vec<_>>();
+    let start = Instant::now();
+    let outcomes = pool.install(|| {
+        solutions
+            .par_iter()
+            .flat_map_iter(|&solution| run_day(year, solution, provider, name, part))
+            .collect()
+    });
+    Ok(Report { year, outcomes, elapsed: start.elapsed() })
+}
+
+fn run_day(
+    year: u32,
+    solution: &dyn AnySolution,
+    provider: &Provider,
+    name: Option<&str>,
+    part: Option<u32>,
+) -> Vec<Outcome> {
+    let day = solution.day();
+    let parts = solution
+        .parts()
+        .iter()
+        .copied()
+        .filter(|&p| part.is_none_or(|part| part == p));
+    let prepared = catch_panic(|| {
+        let parsed = solution.parse(&provider.input(year, day, name)?)?;
+        Ok((parsed, Answers::load(year, day)?))
+    });
+    let (parsed, answers) = match prepared {
+        Ok(prepared) => prepared,
+        Err(err) => {
+            let error = |part| Outcome {
+                day,
+                part,
+                status: Status::Error(format!("{err:#}")),
+                elapsed: Duration::ZERO,
+            };
+            return parts.map(error).collect();
+        }
+    };
+    parts
+        .map(|part| {
+            let start = Instant::now();
+            let res = catch_panic(|| solution.solve(parsed.as_ref(), part, &[]));
+            let elapsed = start.elapsed();
+            let status = match res {
+                Ok(answer) => Status::Solved {
+                    verdict: answers.check(name, part, &answer),
+                    answer,
+                },
+                Err(err) => Status::Error(format!("{err:#}")),
+            };
+            Outcome { day, part, status, elapsed }
+        })
+        .collect()
+}
+
+/// Run `f`, turning a panic into an error with the panic's message.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow!("panicked: {message}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use super::{run_all, run_day, Outcome, Report, Status};
+    use crate::answers::Verdict;
+    use crate::fetch::Provider;
+    use crate::input::Inputs;
+    use crate::solution::{Answer, Erased, Solution};
+
+    /// A day whose part 1 panics.
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u32 = 0;
+        type Input = String;
+        type Params = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part1(_input: &Self::Input, _params: &()) -> Result<Answer> {
+            panic!("part 1 gave up")
+        }
+
+        fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+            Ok(input.trim().into())
+        }
+    }
+
+    fn report() -> Report {
+        let outcome = |day, part, status| Outcome {
+            day,
+            part,
+            status,
+            elapsed: Duration::from_millis(2),
+        };
+        let pass = Status::Solved { answer: Answer::from(142), verdict: Verdict::Pass };
+        let fail = Status::Solved {
+            answer: Answer::from(7),
+            verdict: Verdict::Fail { expected: Answer::from(8) },
+        };
+        Report {
+            year: 2023,
+            outcomes: vec![
+                outcome(1, 1, pass),
+                outcome(1, 2, fail),
+                outcome(2, 1, Status::Error("no input".to_string())),
+            ],
+            elapsed: Duration::from_millis(5),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let report = report();
+        assert_eq!(report.failures(), 2);
+        let text = report.render();
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "Day  Part  Answer  Time     Result");
+        assert_eq!(lines[1], "01   1     142     2.00 ms  pass");
+        assert_eq!(lines[3], "02   1     -       2.00 ms  ERROR: no input");
+        assert_eq!(
+            lines[4],
+            "1 passed, 1 failed, 0 unknown, 1 errors in 5.00 ms"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["year"], 2023);
+        assert_eq!(json["results"][1]["answer"], "7");
+        assert_eq!(json["results"][1]["result"], "fail");
+        assert_eq!(json["results"][1]["detail"], "FAIL (expected 8)");
+        assert_eq!(json["results"][2]["answer"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_run_all_without_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let provider = Provider::new(Inputs::new(&dir), None);
+        let report = run_all(2023, &provider, None, Some(2), 2).unwrap();
+        // Every day but 25 has a part 2, and none has an input.
+        assert_eq!(report.outcomes.len(), 24);
+        assert!(report.outcomes.windows(2).all(|w| w[0].day < w[1].day));
+        assert_eq!(report.failures(), 24);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_run_day_panics() {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-panic-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        std::fs::write(dir.join("2023/day00.txt"), "1\n").unwrap();
+        let provider = Provider::new(Inputs::new(&dir), None);
+        let outcomes = run_day(2023, &Erased::<Panics>::new(), &provider, None, None);
+        assert_eq!(
+            outcomes[0].status,
+            Status::Error("panicked: part 1 gave up".to_string())
+        );
+        // The panic doesn't lose the other part's answer.
+        assert_eq!(outcomes[1].result(), "unknown");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
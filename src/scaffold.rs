@@ -0,0 +1,331 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// The skeleton every new day starts from. It is compiled as `mod template`, so it stays valid,
+/// with its input tests ignored until a new day has an input.
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Example input and the answer it should produce, written to a new day's fixtures.
+#[derive(Clone, Debug, Default)]
+pub struct Example {
+    pub text: Option<String>,
+    pub answer: Option<u64>,
+}
+
+/// Creates new day modules in a crate laid out like this one.
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Scaffold { root: root.into() }
+    }
+
+    /// Write `src/yearYYYY/dayNN.rs` and register it with its year, adding the year to `lib.rs`
+    /// and the runner if it is new, write the examples to `fixtures/YEAR/dayNN/`, and create an
+    /// empty input file if there isn't one. Returns the files created or changed.
+    pub fn create(
+        &self,
+        year: u32,
+        day: u32,
+        input_dir: &Path,
+        examples: &[Example; 2],
+    ) -> Result<Vec<PathBuf>> {
+        if !(1..=25).contains(&day) {
+            bail!("day must be between 1 and 25, got {day}");
+        }
+        let src = self.root.join("src");
+        let year_dir = src.join(format!("year{year}"));
+        let module = year_dir.join(format!("day{day:02}.rs"));
+        if module.exists() {
+            bail!("{} already exists", module.display());
+        }
+
+        // Work out every edit before writing anything, so a failure leaves the tree untouched.
+        let year_mod = year_dir.join("mod.rs");
+        let mut edits = Vec::new();
+        if year_mod.exists() {
+            let text = register_module(&read(&year_mod)?, day)?;
+            edits.push((year_mod, register_solution(&text, day)?));
+        } else {
+            let lib = src.join("lib.rs");
+            let runner = src.join("runner.rs");
+            edits.push((lib.clone(), register_year_module(&read(&lib)?, year)?));
+            edits.push((runner.clone(), register_year(&read(&runner)?, year)?));
+            edits.push((year_mod, year_module(day)));
+        }
+        edits.push((module, render(year, day)?));
+
+        let fixture_dir = self
+            .root
+            .join("fixtures")
+            .join(year.to_string())
+            .join(format!("day{day:02}"));
+        for (name, text) in example_fixtures(examples) {
+            let path = fixture_dir.join(name);
+            if path.exists() {
+                bail!("{} already exists", path.display());
+            }
+            edits.push((path, text));
+        }
+
+        let mut res = Vec::new();
+        for (path, text) in edits {
+            let dir = path.parent().unwrap();
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+            write(&path, &text)?;
+            res.push(path);
+        }
+
+        let input_dir = input_dir.join(year.to_string());
+        let input = input_dir.join(format!("day{day:02}.txt"));
+        if !input.exists() {
+            fs::create_dir_all(&input_dir)
+                .with_context(|| format!("creating {}", input_dir.display()))?;
+            write(&input, "")?;
+            res.push(input);
+        }
+        Ok(res)
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).with_context(|| format!("writing {}", path.display()))
+}
+
+/// Replace one piece of the template, failing loudly if the template has drifted.
+fn substitute(text: String, from: &str, to: &str) -> Result<String> {
+    if !text.contains(from) {
+        bail!("template.rs no longer contains `{from}`");
+    }
+    Ok(text.replace(from, to))
+}
+
+/// The template filled in for `day`.
+pub fn render(year: u32, day: u32) -> Result<String> {
+    let mut res = TEMPLATE.to_string();
+    res = substitute(res, "DayX", &format!("Day{day:02}"))?;
+    res = substitute(res, "//! Day 0.", &format!("//! Day {day}."))?;
+    res = substitute(
+        res,
+        "const DAY: u32 = 0;",
+        &format!("const DAY: u32 = {day};"),
+    )?;
+    res = substitute(
+        res,
+        "read_input(0, 0)",
+        &format!("read_input({year}, {day})"),
+    )?;
+    res = substitute(res, "example_tests!(0,", &format!("example_tests!({year},"))?;
+    res = substitute(res, "    #[ignore = \"the template has no input\"]\n", "")?;
+    Ok(res)
+}
+
+/// The fixture files for a new day's examples, as file names and contents. Part 2's answer goes
+/// with part 1's example unless part 2 has its own.
+pub fn example_fixtures(examples: &[Example; 2]) -> Vec<(String, String)> {
+    let mut res = Vec::new();
+    for (i, example) in examples.iter().enumerate() {
+        let Some(text) = &example.text else {
+            continue;
+        };
+        let n = i + 1;
+        let mut answers = String::new();
+        if let Some(answer) = example.answer {
+            answers += &format!("part{n} = {answer}\n");
+        }
+        if let (1, None, Some(answer)) = (n, &examples[1].text, examples[1].answer) {
+            answers += &format!("part2 = {answer}\n");
+        }
+        let mut text = text.clone();
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        res.push((format!("example{n}.txt"), text));
+        res.push((format!("example{n}.toml"), answers));
+    }
+    res
+}
+
+/// Insert `line` among the lines matching `is_entry`, keeping them sorted.
+fn insert_sorted(text: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        bail!("`{}` is already registered", line.trim());
+    }
+    let Some(last) = lines.iter().rposition(|l| is_entry(l)) else {
+        bail!("found nowhere to register `{}`", line.trim());
+    };
+    let at = lines
+        .iter()
+        .position(|l| is_entry(l) && *l > line)
+        .unwrap_or(last + 1);
+
+    let mut res = lines[..at].join("\n");
+    if at > 0 {
+        res.push('\n');
+    }
+    res += line;
+    for l in &lines[at..] {
+        res.push('\n');
+        res += l;
+    }
+    res.push('\n');
+    Ok(res)
+}
+
+/// Add `pub mod dayNN;` to the module list in a year's `mod.rs`.
+pub fn register_module(year_mod: &str, day: u32) -> Result<String> {
+    let is_day = |l: &str| l.starts_with("pub mod day") && l.ends_with(';');
+    insert_sorted(year_mod, &format!("pub mod day{day:02};"), is_day)
+}
+
+fn solution_entry(day: u32) -> String {
+    format!("    &Erased::<day{day:02}::Day{day:02}>::new(),")
+}
+
+/// Add the day to the dispatch table in a year's `mod.rs`.
+pub fn register_solution(year_mod: &str, day: u32) -> Result<String> {
+    let is_day = |l: &str| l.trim_start().starts_with("&Erased::<day");
+    insert_sorted(year_mod, &solution_entry(day), is_day)
+}
+
+/// The `mod.rs` for a new year whose first day is `day`.
+pub fn year_module(day: u32) -> String {
+    format!(
+        "use crate::solution::{{AnySolution, Erased}};
+
+pub mod day{day:02};
+
+/// The dispatch table, in day order. `aoc new` adds entries here.
+#[rustfmt::skip]
+pub(crate) static SOLUTIONS: &[&dyn AnySolution] = &[
+{}
+];
+",
+        solution_entry(day)
+    )
+}
+
+/// Add `pub mod yearYYYY;` to `lib.rs`.
+pub fn register_year_module(lib: &str, year: u32) -> Result<String> {
+    let is_year = |l: &str| l.starts_with("pub mod year") && l.ends_with(';');
+    insert_sorted(lib, &format!("pub mod year{year};"), is_year)
+}
+
+/// Add the year's dispatch table to the runner.
+pub fn register_year(runner: &str, year: u32) -> Result<String> {
+    let is_year = |l: &str| l.trim_start().starts_with('(') && l.ends_with("::SOLUTIONS),");
+    let entry = format!("    ({year}, crate::year{year}::SOLUTIONS),");
+    insert_sorted(runner, &entry, is_year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        example_fixtures, register_module, register_solution, register_year, register_year_module,
+        render, year_module, Example,
+    };
+
+    #[test]
+    fn test_render() {
+        let res = render(2023, 9).unwrap();
+        assert!(res.starts_with("//! Day 9.\n"));
+        assert!(res.contains("pub struct Day09;"));
+        assert!(res.contains("const DAY: u32 = 9;"));
+        assert!(res.contains("read_input(2023, 9)"));
+        assert!(res.contains("example_tests!(2023, Day09);"));
+        assert!(!res.contains("#[ignore"));
+    }
+
+    #[test]
+    fn test_example_fixtures() {
+        let examples = [
+            Example {
+                text: Some("1 \"2\"".to_string()),
+                answer: Some(42),
+            },
+            Example { text: None, answer: Some(7) },
+        ];
+        assert_eq!(
+            example_fixtures(&examples),
+            [
+                ("example1.txt".to_string(), "1 \"2\"\n".to_string()),
+                (
+                    "example1.toml".to_string(),
+                    "part1 = 42\npart2 = 7\n".to_string()
+                ),
+            ]
+        );
+
+        let examples = [
+            Example { text: Some("a\n".to_string()), answer: None },
+            Example { text: Some("b\n".to_string()), answer: Some(7) },
+        ];
+        let files = example_fixtures(&examples);
+        assert_eq!(files[1], ("example1.toml".to_string(), String::new()));
+        assert_eq!(
+            files[3],
+            ("example2.toml".to_string(), "part2 = 7\n".to_string())
+        );
+        assert!(example_fixtures(&Default::default()).is_empty());
+    }
+
+    #[test]
+    fn test_register() {
+        let lib = "pub mod answers;\npub mod day01;\npub mod day03;\npub mod grid;\n";
+        assert_eq!(
+            register_module(lib, 2).unwrap(),
+            "pub mod answers;\npub mod day01;\npub mod day02;\npub mod day03;\npub mod grid;\n"
+        );
+        assert_eq!(
+            register_module(lib, 4).unwrap(),
+            "pub mod answers;\npub mod day01;\npub mod day03;\npub mod day04;\npub mod grid;\n"
+        );
+        assert!(register_module(lib, 3).is_err());
+
+        let year_mod = "pub(crate) static SOLUTIONS: &[&dyn AnySolution] = &[
+    &Erased::<day11::Day11>::new(),
+];
+";
+        assert_eq!(
+            register_solution(year_mod, 5).unwrap(),
+            "pub(crate) static SOLUTIONS: &[&dyn AnySolution] = &[
+    &Erased::<day05::Day05>::new(),
+    &Erased::<day11::Day11>::new(),
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = "pub mod solution;\nmod template;\npub mod util;\npub mod year2023;\n";
+        assert_eq!(
+            register_year_module(lib, 2024).unwrap(),
+            "pub mod solution;\nmod template;\npub mod util;\npub mod year2023;\npub mod year2024;\n"
+        );
+        let runner = "static YEARS: &[(u32, &[&dyn AnySolution])] = &[
+    (2023, crate::year2023::SOLUTIONS),
+];
+";
+        let runner = register_year(runner, 2024).unwrap();
+        assert!(runner.ends_with(
+            "    (2023, crate::year2023::SOLUTIONS),\n    (2024, crate::year2024::SOLUTIONS),\n];\n"
+        ));
+
+        let year_mod = register_module(&year_module(3), 1).unwrap();
+        let year_mod = register_solution(&year_mod, 1).unwrap();
+        assert!(year_mod.contains("pub mod day01;\npub mod day03;\n"));
+        assert!(year_mod.contains(
+            "    &Erased::<day01::Day01>::new(),\n    &Erased::<day03::Day03>::new(),\n];"
+        ));
+    }
+}
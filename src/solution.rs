@@ -0,0 +1,234 @@
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+
+use anyhow::{anyhow, bail, Result};
+use rand::rngs::StdRng;
+use tracing::info_span;
+
+use crate::gen;
+use crate::graphviz::Dot;
+use crate::viz::Frame;
+
+/// A puzzle answer. Almost every answer is a number, but text is allowed for the odd puzzle that
+/// answers with a word.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// The knobs a day needs beyond its input, such as day 11's expansion multiplier. Defaults are
+/// the values the real puzzle uses; examples override them with `set`.
+pub trait Parameters: Default {
+    fn set(&mut self, key: &str, value: &str) -> Result<()>;
+}
+
+impl Parameters for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<()> {
+        bail!("unknown parameter `{key}`")
+    }
+}
+
+/// A day's solution: parse the input once, then solve either part from the parsed form.
+pub trait Solution {
+    const DAY: u32;
+    /// Day 25 only has one part.
+    const HAS_PART2: bool = true;
+
+    type Input: Send + Sync + 'static;
+    type Params: Parameters;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input, _params: &Self::Params) -> Result<Answer> {
+        Err(anyhow!("day {} has no part 2", Self::DAY))
+    }
+
+    /// Pictures for `aoc viz`: a single frame, or one per step of a simulation. Most days have
+    /// none.
+    fn visualise(_input: &Self::Input, _params: &Self::Params) -> Result<Vec<Frame>> {
+        Err(anyhow!("day {} has no visualisation", Self::DAY))
+    }
+
+    /// The puzzle's graph for `aoc dot`, on days whose puzzle is one.
+    fn dot(_input: &Self::Input, _params: &Self::Params) -> Result<Dot> {
+        Err(anyhow!("day {} has no graph", Self::DAY))
+    }
+
+    /// Text for `aoc report` answering a question the puzzle raises beyond its two parts, such
+    /// as day 2's smallest bag for a choice of games.
+    fn report(_input: &Self::Input, _params: &Self::Params) -> Result<String> {
+        Err(anyhow!("day {} has no report", Self::DAY))
+    }
+
+    /// A random input for `aoc gen`, of a size whose meaning is up to the day, e.g. lines of
+    /// input or the side of a grid.
+    fn generate(_rng: &mut StdRng, _size: usize) -> Result<String> {
+        Err(anyhow!("day {} has no generator", Self::DAY))
+    }
+}
+
+/// Object-safe view of a `Solution` so the runner can keep every day in one table.
+pub trait AnySolution: Sync {
+    fn day(&self) -> u32;
+
+    fn parts(&self) -> &'static [u32];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>>;
+
+    fn solve(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        part: u32,
+        params: &[(String, String)],
+    ) -> Result<Answer>;
+
+    fn visualise(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        params: &[(String, String)],
+    ) -> Result<Vec<Frame>>;
+
+    fn dot(&self, input: &(dyn Any + Send + Sync), params: &[(String, String)]) -> Result<Dot>;
+
+    fn report(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        params: &[(String, String)],
+    ) -> Result<String>;
+
+    /// The same seed and size always give the same input.
+    fn generate(&self, seed: u64, size: usize) -> Result<String>;
+}
+
+pub struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> Erased<S> {
+    pub const fn new() -> Self {
+        Erased(PhantomData)
+    }
+}
+
+impl<S> Default for Erased<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> AnySolution for Erased<S> {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [u32] {
+        if S::HAS_PART2 {
+            &[1, 2]
+        } else {
+            &[1]
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>> {
+        let _span = info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        part: u32,
+        params: &[(String, String)],
+    ) -> Result<Answer> {
+        let _span = info_span!("solve", day = S::DAY, part).entered();
+        let (input, parameters) = prepare::<S>(input, params)?;
+        match part {
+            1 => S::part1(input, &parameters),
+            2 if S::HAS_PART2 => S::part2(input, &parameters),
+            _ => bail!("day {} has no part {part}", S::DAY),
+        }
+    }
+
+    fn visualise(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        params: &[(String, String)],
+    ) -> Result<Vec<Frame>> {
+        let _span = info_span!("visualise", day = S::DAY).entered();
+        let (input, parameters) = prepare::<S>(input, params)?;
+        S::visualise(input, &parameters)
+    }
+
+    fn dot(&self, input: &(dyn Any + Send + Sync), params: &[(String, String)]) -> Result<Dot> {
+        let _span = info_span!("dot", day = S::DAY).entered();
+        let (input, parameters) = prepare::<S>(input, params)?;
+        S::dot(input, &parameters)
+    }
+
+    fn report(
+        &self,
+        input: &(dyn Any + Send + Sync),
+        params: &[(String, String)],
+    ) -> Result<String> {
+        let _span = info_span!("report", day = S::DAY).entered();
+        let (input, parameters) = prepare::<S>(input, params)?;
+        S::report(input, &parameters)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Result<String> {
+        let _span = info_span!("generate", day = S::DAY, seed, size).entered();
+        S::generate(&mut gen::rng(seed), size)
+    }
+}
+
+/// Recover a day's parsed input and build its parameters.
+fn prepare<'a, S: Solution>(
+    input: &'a (dyn Any + Send + Sync),
+    params: &[(String, String)],
+) -> Result<(&'a S::Input, S::Params)> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("input was not parsed by day {}", S::DAY))?;
+    let mut parameters = S::Params::default();
+    for (key, value) in params {
+        parameters.set(key, value)?;
+    }
+    Ok((input, parameters))
+}
@@ -0,0 +1,36 @@
+/// Lay rows out as a table with the first row as its header, either as aligned plain text or as
+/// markdown.
+pub fn render(rows: &[Vec<String>], markdown: bool) -> String {
+    let Some(header) = rows.first() else {
+        return String::new();
+    };
+    let widths = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let pad = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+    };
+
+    let mut res = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        if markdown {
+            res += &format!("| {} |\n", pad(row).join(" | "));
+            if i == 0 {
+                let rule = widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>();
+                res += &format!("| {} |\n", rule.join(" | "));
+            }
+        } else {
+            res += pad(row).join("  ").trim_end();
+            res.push('\n');
+        }
+    }
+    res
+}
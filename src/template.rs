@@ -1,49 +1,63 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-// use std::str::FromStr;
-fn read_file() -> impl Iterator<Item = String> {
-    let file = File::open("input/dayX.txt").unwrap();
-    BufReader::new(file).lines().map(|s| s.unwrap())
+//! Day 0.
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(|s| s.to_string()).collect_vec())
 }
 
-fn part1(_input: impl Iterator<Item = String>) -> u64 {
+pub fn part1(_input: &[String]) -> u64 {
     unimplemented!()
 }
 
-fn part2(_input: impl Iterator<Item = String>) -> u64 {
+pub fn part2(_input: &[String]) -> u64 {
     unimplemented!()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{part1, part2, read_file};
+pub struct DayX;
 
-    const EXAMPLE1: &str = "
-";
+impl Solution for DayX {
+    const DAY: u32 = 0;
+    type Input = Vec<String>;
+    type Params = ();
 
-    #[test]
-    fn test_part1_example() {
-        assert_eq!(part1(EXAMPLE1.lines().map(|v| v.to_string())), 0);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
     }
 
-    #[test]
-    fn test_part1() {
-        let res = part1(read_file());
-        println!("{}", res);
-        // assert_eq!(res, 0);
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
     }
 
-    const EXAMPLE2: &str = "
-";
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, DayX};
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+
+    example_tests!(0, DayX);
 
     #[test]
-    fn test_part2_example() {
-        assert_eq!(part2(EXAMPLE2.lines().map(|v| v.to_string())), 0);
+    #[ignore = "the template has no input"]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(0, 0).unwrap()).unwrap());
+        println!("{}", res);
+        // assert_eq!(res, 0);
     }
 
     #[test]
+    #[ignore = "the template has no input"]
     fn test_part2() {
-        let res = part2(read_file());
+        let res = part2(&parse_input(&read_input(0, 0).unwrap()).unwrap());
         println!("{}", res);
         // assert_eq!(res, 0);
     }
@@ -9,6 +9,156 @@ pub fn gcd(a: u64, b: u64) -> u64 {
     a
 }
 
+/// Panics if the result doesn't fit in a `u64`; see `checked_lcm`.
 pub fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({a}, {b}) overflows u64"))
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`. Zero if either is zero.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of two `u64`s, which always fits in a `u128`.
+pub fn lcm_u128(a: u64, b: u64) -> u128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)) as u128 * b as u128
+}
+
+/// Returns `(g, x, y)` where `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Solve the system `t ≡ offset (mod period)` for every `(offset, period)` pair, as with cycles
+/// that each start at `offset` and repeat every `period` steps. Periods need not be coprime.
+///
+/// Returns the combined `(offset, period)`, with the offset the smallest non-negative solution
+/// and the period the lcm of the periods, or `None` if the cycles never line up or the period
+/// overflows. An empty system is `(0, 1)`: every `t` is a solution.
+pub fn crt(pairs: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut res = (0, 1);
+    for (offset, period) in pairs {
+        if period <= 0 {
+            return None;
+        }
+        let (a, m) = res;
+        let b = offset.rem_euclid(period);
+        // Find k with a + m * k ≡ b (mod period).
+        let (g, inv, _) = extended_gcd(m, period);
+        if (b - a) % g != 0 {
+            return None;
+        }
+        let step = period / g;
+        let k = ((b - a) / g)
+            .rem_euclid(step)
+            .checked_mul(inv.rem_euclid(step))?
+            % step;
+        let lcm = m.checked_mul(step)?;
+        res = ((a + m.checked_mul(k)?).rem_euclid(lcm), lcm);
+    }
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{checked_lcm, crt, extended_gcd, gcd, lcm, lcm_u128, mod_inverse};
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        // a * b alone would overflow, but the lcm fits.
+        assert_eq!(checked_lcm(1 << 40, 3 << 40), Some(3 << 40));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(
+            lcm_u128(u64::MAX, u64::MAX - 1),
+            u64::MAX as u128 * (u64::MAX - 1) as u128
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_lcm(a in 1..u64::MAX, b in 1..u64::MAX) {
+            let wide = lcm_u128(a, b);
+            prop_assert_eq!(wide % a as u128, 0);
+            prop_assert_eq!(wide % b as u128, 0);
+            prop_assert_eq!(wide * gcd(a, b) as u128, a as u128 * b as u128);
+            let fits = u64::try_from(wide).ok();
+            prop_assert_eq!(checked_lcm(a, b), fits);
+        }
+
+        #[test]
+        fn prop_extended_gcd(
+            a in -1_000_000_000i128..1_000_000_000,
+            b in -1_000_000_000i128..1_000_000_000,
+        ) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64));
+        }
+
+        #[test]
+        fn prop_mod_inverse(a in -1_000_000i128..1_000_000, m in 1i128..1_000_000) {
+            match mod_inverse(a, m) {
+                Some(inv) => {
+                    prop_assert!((0..m).contains(&inv));
+                    prop_assert_eq!((a * inv).rem_euclid(m), 1 % m);
+                }
+                None => prop_assert_ne!(extended_gcd(a, m).0, 1),
+            }
+        }
+
+        #[test]
+        fn prop_crt(pairs in prop::collection::vec((0i128..50, 1i128..12), 0..4)) {
+            let lcm = pairs.iter().fold(1, |acc, &(_, p)| lcm(acc as u64, p as u64) as i128);
+            // Brute force over one full period of the combined system.
+            let expected = (0..lcm)
+                .find(|t| pairs.iter().all(|&(o, p)| (t - o).rem_euclid(p) == 0));
+            prop_assert_eq!(crt(pairs.iter().copied()), expected.map(|t| (t, lcm)));
+        }
+    }
 }
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+use crate::grid::{Grid, Pos};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [96, 96, 96];
+pub const RED: Rgb = [220, 50, 47];
+pub const ORANGE: Rgb = [203, 75, 22];
+pub const YELLOW: Rgb = [230, 190, 40];
+pub const GREEN: Rgb = [90, 170, 60];
+pub const BLUE: Rgb = [38, 139, 210];
+
+/// Blend from `a` at `t = 0` to `b` at `t = 1`.
+pub fn mix(a: Rgb, b: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0., 1.);
+    [0, 1, 2].map(|i| (a[i] as f64 + (b[i] as f64 - a[i] as f64) * t).round() as u8)
+}
+
+/// Cold to hot: dark blue, through red, to yellow.
+pub fn heat(t: f64) -> Rgb {
+    if t < 0.5 {
+        mix([20, 20, 80], RED, t * 2.)
+    } else {
+        mix(RED, YELLOW, t * 2. - 1.)
+    }
+}
+
+/// A picture of a grid with a colour per cell, drawn as a square block of pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Rgb>,
+}
+
+impl Frame {
+    /// The base layer, colouring each cell of the grid.
+    pub fn new<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Frame { cells: grid.map(color) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<Rgb> {
+        self.cells.get(pos).copied()
+    }
+
+    /// Paint over some cells. Cells off the grid are ignored.
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = Pos>, color: Rgb) -> &mut Self {
+        for pos in cells {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                *cell = color;
+            }
+        }
+        self
+    }
+
+    /// Paint a path, shading from `from` at its start to `to` at its end.
+    pub fn path(&mut self, path: &[Pos], from: Rgb, to: Rgb) -> &mut Self {
+        let last = path.len().saturating_sub(1).max(1) as f64;
+        for (i, &pos) in path.iter().enumerate() {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                *cell = mix(from, to, i as f64 / last);
+            }
+        }
+        self
+    }
+
+    /// Paint cells with a heat map of their values, scaled from the smallest value to the largest.
+    pub fn heat_map(&mut self, values: impl IntoIterator<Item = (Pos, f64)>) -> &mut Self {
+        let values = values.into_iter().collect::<Vec<_>>();
+        let min = values.iter().map(|&(_, v)| v).fold(f64::INFINITY, f64::min);
+        let max = values
+            .iter()
+            .map(|&(_, v)| v)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let range = if max > min { max - min } else { 1. };
+        for (pos, value) in values {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                *cell = heat((value - min) / range);
+            }
+        }
+        self
+    }
+
+    /// Row-major RGB bytes, each cell `scale` pixels square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for color in row {
+                    for _ in 0..scale {
+                        res.extend(color);
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// A binary PPM (P6) image.
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut res = format!("P6\n{width} {height}\n255\n").into_bytes();
+        res.extend(self.pixels(scale));
+        res
+    }
+
+    pub fn png(&self, scale: usize) -> Result<Vec<u8>> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut res = Vec::new();
+        let mut encoder = png::Encoder::new(&mut res, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels(scale))?;
+        writer.finish()?;
+        Ok(res)
+    }
+}
+
+/// An animated GIF showing each frame for `delay`, looping forever. The frames must all be the
+/// same size.
+pub fn gif(frames: &[Frame], scale: usize, delay: Duration) -> Result<Vec<u8>> {
+    let Some(first) = frames.first() else {
+        bail!("no frames to animate");
+    };
+    let (width, height) = (first.width() * scale, first.height() * scale);
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        bail!("{width}x{height} is too large for a GIF");
+    }
+    let mut res = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut res, width as u16, height as u16, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in frames {
+            if (frame.width(), frame.height()) != (first.width(), first.height()) {
+                bail!("frames differ in size");
+            }
+            let pixels = frame.pixels(scale);
+            let mut image = match palette(&pixels) {
+                Some((palette, indices)) => gif::Frame::from_palette_pixels(
+                    width as u16,
+                    height as u16,
+                    indices,
+                    palette,
+                    None,
+                ),
+                // More than 256 colours, so let the encoder pick a palette.
+                None => gif::Frame::from_rgb_speed(width as u16, height as u16, &pixels, 10),
+            };
+            image.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+            encoder.write_frame(&image)?;
+        }
+    }
+    Ok(res)
+}
+
+/// The distinct colours of RGB pixels and each pixel's index into them, if there are at most 256.
+fn palette(pixels: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut colors: HashMap<&[u8], u8> = HashMap::new();
+    let mut palette = Vec::new();
+    let mut indices = Vec::with_capacity(pixels.len() / 3);
+    for pixel in pixels.chunks(3) {
+        let index = match colors.get(pixel) {
+            Some(&index) => index,
+            None if colors.len() == 256 => return None,
+            None => {
+                let index = colors.len() as u8;
+                colors.insert(pixel, index);
+                palette.extend(pixel);
+                index
+            }
+        };
+        indices.push(index);
+    }
+    Some((palette, indices))
+}
+
+/// Write frames to `path`, in the format its extension names. A `.gif` animates every frame; a
+/// `.png` or `.ppm` holds a single frame, or with several frames is numbered, e.g.
+/// `day14-003.png`. Returns the files written.
+pub fn save(frames: &[Frame], path: &Path, scale: usize, delay: Duration) -> Result<Vec<PathBuf>> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let encode = |frame: &Frame| match extension {
+        "png" => frame.png(scale),
+        "ppm" => Ok(frame.ppm(scale)),
+        _ => bail!("unknown image format `{extension}`: use .png, .ppm or .gif"),
+    };
+    let images = match (extension, frames) {
+        ("gif", _) => vec![(path.to_path_buf(), gif(frames, scale, delay)?)],
+        (_, [frame]) => vec![(path.to_path_buf(), encode(frame)?)],
+        _ => {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+            let digits = frames.len().to_string().len().max(3);
+            frames
+                .iter()
+                .enumerate()
+                .map(|(i, frame)| {
+                    let name = format!("{stem}-{i:0digits$}.{extension}");
+                    Ok((path.with_file_name(name), encode(frame)?))
+                })
+                .collect::<Result<_>>()?
+        }
+    };
+    let mut res = Vec::new();
+    for (path, bytes) in images {
+        fs::write(&path, bytes).with_context(|| format!("writing {}", path.display()))?;
+        res.push(path);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    use super::{gif, heat, mix, Frame, BLACK, RED, WHITE, YELLOW};
+    use crate::grid::{Grid, Pos};
+
+    fn frame() -> Frame {
+        let grid = Grid::parse(0, "#.\n..\n", "#.").unwrap();
+        Frame::new(&grid, |&c| if c == '#' { WHITE } else { BLACK })
+    }
+
+    #[test]
+    fn test_overlays() {
+        assert_eq!(mix(BLACK, WHITE, 0.5), [128, 128, 128]);
+        assert_eq!((heat(0.5), heat(1.)), (RED, YELLOW));
+
+        let mut frame = frame();
+        frame.highlight([Pos::new(1, 1), Pos::new(5, 5)], RED).path(
+            &[Pos::new(1, 0), Pos::new(0, 1)],
+            BLACK,
+            WHITE,
+        );
+        assert_eq!(frame.get(Pos::new(1, 1)), Some(RED));
+        assert_eq!(frame.get(Pos::new(0, 1)), Some(WHITE));
+        frame.heat_map([(Pos::new(0, 0), 3.), (Pos::new(1, 1), 7.)]);
+        assert_eq!(frame.get(Pos::new(1, 1)), Some(YELLOW));
+    }
+
+    #[test]
+    fn test_encode() {
+        let ppm = frame().ppm(2);
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        // The top row is two white pixels then two black.
+        assert_eq!(
+            &ppm[11..23],
+            [255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0]
+        );
+
+        let png = frame().png(3).unwrap();
+        let reader = png::Decoder::new(Cursor::new(png)).read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (6, 6));
+
+        let animation = gif(&[frame(), frame()], 1, Duration::from_millis(50)).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(Cursor::new(animation))
+            .unwrap();
+        let mut frames = 0;
+        while let Some(image) = decoder.read_next_frame().unwrap() {
+            assert_eq!((image.width, image.height, image.delay), (2, 2, 5));
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
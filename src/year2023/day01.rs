@@ -0,0 +1,481 @@
+//! Day 1: Trebuchet?! Calibration values made of the first and last digit on each line.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::warn;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Parameters, Solution};
+
+/// The calibration document, one line per string.
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(|s| s.to_string()).collect_vec())
+}
+
+/// The digits spelled out in English, indexed by value.
+pub const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Words and the digits they stand for, e.g. "7" and "seven" for 7.
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    words: Vec<(String, u64)>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Vocabulary::default()
+    }
+
+    /// Add a word for `digit`. A word already in the vocabulary keeps its first digit.
+    pub fn word(mut self, word: &str, digit: u64) -> Self {
+        self.words.push((word.to_string(), digit));
+        self
+    }
+
+    /// Add the numerals `0` to `9`.
+    pub fn numerals(self) -> Self {
+        (0..10).fold(self, |vocabulary, digit| {
+            vocabulary.word(&digit.to_string(), digit)
+        })
+    }
+
+    /// Add the words for one to nine in some language, indexed by value as in `ENGLISH`, and the
+    /// word for zero if `zero`.
+    pub fn spelled(self, words: &[&str; 10], zero: bool) -> Self {
+        let from = if zero { 0 } else { 1 };
+        (from..10).fold(self, |vocabulary, digit| {
+            vocabulary.word(words[digit], digit as u64)
+        })
+    }
+}
+
+/// An Aho-Corasick automaton over a set of words, reading a byte at a time.
+#[derive(Clone, Debug)]
+struct Automaton {
+    /// The state after each byte from each state, with the failure links already followed.
+    next: Vec<[u32; 256]>,
+    /// The words ending at each state, as their digit and length, longest first.
+    ends: Vec<Vec<(u64, usize)>>,
+    longest: usize,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<u8>, u64)]) -> Self {
+        const NONE: u32 = u32::MAX;
+        let mut next = vec![[NONE; 256]];
+        let mut ends = vec![Vec::new()];
+        for (word, digit) in words.iter().filter(|(word, _)| !word.is_empty()) {
+            let mut state = 0;
+            for &b in word {
+                if next[state][b as usize] == NONE {
+                    next[state][b as usize] = next.len() as u32;
+                    next.push([NONE; 256]);
+                    ends.push(Vec::new());
+                }
+                state = next[state][b as usize] as usize;
+            }
+            if ends[state].is_empty() {
+                ends[state].push((*digit, word.len()));
+            }
+        }
+
+        // Breadth first, so each state's failure link is complete before its children need it.
+        let mut fail = vec![0; next.len()];
+        let mut q = VecDeque::from([0]);
+        while let Some(state) = q.pop_front() {
+            let fallbacks = if state == 0 { [0; 256] } else { next[fail[state]] };
+            for (b, fallback) in fallbacks.into_iter().enumerate() {
+                let child = next[state][b];
+                if child == NONE {
+                    next[state][b] = fallback;
+                } else {
+                    let child = child as usize;
+                    fail[child] = fallback as usize;
+                    let inherited = ends[fail[child]].clone();
+                    ends[child].extend(inherited);
+                    ends[child].sort_by_key(|&(_, len)| Reverse(len));
+                    q.push_back(child);
+                }
+            }
+        }
+        let longest = words.iter().map(|(word, _)| word.len()).max().unwrap_or(0);
+        Automaton { next, ends, longest }
+    }
+
+    /// Every word in `bytes`, overlapping ones included, as its start, length and digit, in the
+    /// order the words end.
+    fn scan(&self, bytes: &[u8]) -> Vec<(usize, usize, u64)> {
+        let mut res = Vec::new();
+        let mut state = 0;
+        for (i, &b) in bytes.iter().enumerate() {
+            state = self.next[state][b as usize] as usize;
+            for &(digit, len) in &self.ends[state] {
+                res.push((i + 1 - len, len, digit));
+            }
+        }
+        res
+    }
+
+    /// The digit of the word that starts first, the longest if several do. Stops reading once no
+    /// word could start any earlier.
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<u64> {
+        let mut best: Option<(usize, usize, u64)> = None;
+        let mut state = 0;
+        for (i, b) in bytes.enumerate() {
+            state = self.next[state][b as usize] as usize;
+            for &(digit, len) in &self.ends[state] {
+                let start = i + 1 - len;
+                if best.is_none_or(|(s, l, _)| start < s || (start == s && len > l)) {
+                    best = Some((start, len, digit));
+                }
+            }
+            if best.is_some_and(|(s, _, _)| i + 1 >= s + self.longest) {
+                break;
+            }
+        }
+        best.map(|(_, _, digit)| digit)
+    }
+}
+
+/// Finds a vocabulary's digits in a line in one pass, however many words it has. Overlapping
+/// words all count, so "eightwo" holds an 8 and a 2.
+#[derive(Clone, Debug)]
+pub struct DigitMatcher {
+    forward: Automaton,
+    /// The words reversed, for reading lines from the right.
+    backward: Automaton,
+}
+
+impl DigitMatcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let words = vocabulary
+            .words
+            .iter()
+            .map(|(word, digit)| (word.as_bytes().to_vec(), *digit))
+            .collect_vec();
+        let reversed = words
+            .iter()
+            .map(|(word, digit)| (word.iter().rev().copied().collect(), *digit))
+            .collect_vec();
+        DigitMatcher {
+            forward: Automaton::new(&words),
+            backward: Automaton::new(&reversed),
+        }
+    }
+
+    /// Every digit in the line in the order their words start. Where two words start together,
+    /// the longer one counts.
+    pub fn digits(&self, line: &str) -> Vec<u64> {
+        self.forward
+            .scan(line.as_bytes())
+            .into_iter()
+            .sorted_by_key(|&(start, len, _)| (start, Reverse(len)))
+            .dedup_by(|a, b| a.0 == b.0)
+            .map(|(_, _, digit)| digit)
+            .collect()
+    }
+
+    /// The digit whose word starts furthest left.
+    pub fn first(&self, line: &str) -> Option<u64> {
+        self.forward.leftmost(line.bytes())
+    }
+
+    /// The digit whose word ends furthest right, reading the line from the right.
+    pub fn last(&self, line: &str) -> Option<u64> {
+        self.backward.leftmost(line.bytes().rev())
+    }
+}
+
+/// A line's calibration value, made of its first digit and its last.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Calibration {
+    Value(u64),
+    /// The line has no digits.
+    Missing,
+}
+
+/// The calibration value of each line.
+pub fn calibrations<'a>(
+    input: &'a [String],
+    matcher: &'a DigitMatcher,
+) -> impl Iterator<Item = Calibration> + 'a {
+    input
+        .iter()
+        .map(|line| match (matcher.first(line), matcher.last(line)) {
+            (Some(first), Some(last)) => Calibration::Value(first * 10 + last),
+            _ => Calibration::Missing,
+        })
+}
+
+/// What to do with a line that has no digits.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MissingPolicy {
+    /// Leave it out of the sum.
+    Skip,
+    /// Count it as zero.
+    Zero,
+    /// Fail, naming the line.
+    #[default]
+    Error,
+}
+
+/// The sum of the calibration values and what went into it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub total: u64,
+    /// The lines in the sum, including any counted as zero.
+    pub counted: usize,
+    /// The lines with no digits, numbered from 1.
+    pub missing: Vec<usize>,
+}
+
+/// Sum the calibration values, dealing with lines that have no digits as `policy` says.
+pub fn calibrate(
+    input: &[String],
+    matcher: &DigitMatcher,
+    policy: MissingPolicy,
+) -> Result<Report> {
+    let mut report = Report::default();
+    for (i, calibration) in calibrations(input, matcher).enumerate() {
+        match (calibration, policy) {
+            (Calibration::Value(value), _) => report.total += value,
+            (Calibration::Missing, MissingPolicy::Error) => {
+                bail!("line {} has no digits: `{}`", i + 1, input[i])
+            }
+            (Calibration::Missing, MissingPolicy::Skip) => {
+                report.missing.push(i + 1);
+                continue;
+            }
+            (Calibration::Missing, MissingPolicy::Zero) => report.missing.push(i + 1),
+        }
+        report.counted += 1;
+    }
+    Ok(report)
+}
+
+/// The sum of the calibration values, counting only numeric digits.
+pub fn part1(input: &[String], policy: MissingPolicy) -> Result<Report> {
+    calibrate(
+        input,
+        &DigitMatcher::new(&Vocabulary::new().numerals()),
+        policy,
+    )
+}
+
+/// The sum of the calibration values, counting digits spelled out in English too.
+pub fn part2(input: &[String], policy: MissingPolicy) -> Result<Report> {
+    let vocabulary = Vocabulary::new().numerals().spelled(&ENGLISH, false);
+    calibrate(input, &DigitMatcher::new(&vocabulary), policy)
+}
+
+/// What to do with lines that have no digits: `skip`, `zero` or `error`, the default.
+#[derive(Default)]
+pub struct Params {
+    pub missing: MissingPolicy,
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match (key, value) {
+            ("missing", "skip") => self.missing = MissingPolicy::Skip,
+            ("missing", "zero") => self.missing = MissingPolicy::Zero,
+            ("missing", "error") => self.missing = MissingPolicy::Error,
+            ("missing", _) => bail!("expected `skip`, `zero` or `error`, got `{value}`"),
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// The report's total, after noting any lines that had no digits.
+fn total(report: Report) -> Answer {
+    if !report.missing.is_empty() {
+        warn!(lines = ?report.missing, "lines with no digits");
+    }
+    report.total.into()
+}
+
+/// `size` lines of letters, digits and spelled-out digits, each with at least one digit.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(2..10) {
+            match rng.gen_range(0..3) {
+                0 => line.push(rng.gen_range('a'..='z')),
+                1 => line.push_str(ENGLISH[rng.gen_range(1..10)]),
+                _ => line.push(rng.gen_range('1'..='9')),
+            }
+        }
+        let at = rng.gen_range(0..=line.len());
+        line.insert(at, rng.gen_range('1'..='9'));
+        res.push_str(&line);
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<String>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(total(part1(input, params.missing)?))
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(total(part2(input, params.missing)?))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{
+        calibrate, calibrations, parse_input, part1, part2, Calibration, Day01, DigitMatcher,
+        MissingPolicy, Report, Vocabulary, ENGLISH,
+    };
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    const GERMAN: [&str; 10] = [
+        "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+    ];
+
+    fn english() -> Vocabulary {
+        Vocabulary::new().numerals().spelled(&ENGLISH, true)
+    }
+
+    /// Try every word at every position of the line, keeping the longest at each.
+    fn brute_force(vocabulary: &Vocabulary, line: &str) -> Vec<u64> {
+        (0..line.len())
+            .filter_map(|i| {
+                let suffix = &line.as_bytes()[i..];
+                vocabulary
+                    .words
+                    .iter()
+                    .filter(|(word, _)| suffix.starts_with(word.as_bytes()))
+                    .max_by_key(|(word, _)| word.len())
+                    .map(|&(_, digit)| digit)
+            })
+            .collect()
+    }
+
+    example_tests!(2023, Day01);
+
+    #[test]
+    fn test_overlapping() {
+        let matcher = DigitMatcher::new(&english());
+        assert_eq!(matcher.digits("eightwothree"), [8, 2, 3]);
+        assert_eq!(matcher.digits("xtwone3four"), [2, 1, 3, 4]);
+        assert_eq!(matcher.first("zoneight234"), Some(1));
+        assert_eq!(matcher.last("zoneight"), Some(8));
+        assert_eq!(matcher.first("zero1"), Some(0));
+        assert_eq!(matcher.first("abc"), None);
+
+        let matcher = DigitMatcher::new(&Vocabulary::new().numerals().spelled(&ENGLISH, false));
+        assert_eq!(matcher.digits("zero1"), [1]);
+    }
+
+    #[test]
+    fn test_other_languages() {
+        let matcher = DigitMatcher::new(&Vocabulary::new().spelled(&GERMAN, true));
+        assert_eq!(matcher.digits("siebenullfünfzwei"), [7, 0, 5, 2]);
+        assert_eq!(matcher.first("xfünfzehn"), Some(5));
+        assert_eq!(matcher.last("achtzweins"), Some(1));
+    }
+
+    #[test]
+    fn test_nested_words() {
+        // A word inside a longer one: the longer starts first, the shorter ends last.
+        let matcher = DigitMatcher::new(&Vocabulary::new().word("abcd", 1).word("bc", 2));
+        assert_eq!(matcher.digits("abcd"), [1, 2]);
+        assert_eq!(matcher.first("abcd"), Some(1));
+        assert_eq!(matcher.last("abcd"), Some(1));
+        assert_eq!(matcher.last("abc"), Some(2));
+    }
+
+    #[test]
+    fn test_missing() {
+        let input = parse_input("a1b\nxyz\n2seven\n\n").unwrap();
+        let matcher = DigitMatcher::new(&english());
+        assert_eq!(
+            calibrations(&input, &matcher).collect::<Vec<_>>(),
+            [
+                Calibration::Value(11),
+                Calibration::Missing,
+                Calibration::Value(27),
+                Calibration::Missing
+            ]
+        );
+        let report = calibrate(&input, &matcher, MissingPolicy::Skip).unwrap();
+        assert_eq!(
+            report,
+            Report { total: 38, counted: 2, missing: vec![2, 4] }
+        );
+        let report = calibrate(&input, &matcher, MissingPolicy::Zero).unwrap();
+        assert_eq!(
+            report,
+            Report { total: 38, counted: 4, missing: vec![2, 4] }
+        );
+        let err = calibrate(&input, &matcher, MissingPolicy::Error).unwrap_err();
+        assert_eq!(err.to_string(), "line 2 has no digits: `xyz`");
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(
+            &parse_input(&read_input(2023, 1).unwrap()).unwrap(),
+            MissingPolicy::Error,
+        )
+        .unwrap()
+        .total;
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 1, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_input(&read_input(2023, 1).unwrap()).unwrap(),
+            MissingPolicy::Error,
+        )
+        .unwrap()
+        .total;
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 1, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_digits(line in "(one|two|three|four|five|six|seven|eight|nine|zero|[a-z0-9]){0,12}") {
+            let vocabulary = english();
+            let matcher = DigitMatcher::new(&vocabulary);
+            let expected = brute_force(&vocabulary, &line);
+            prop_assert_eq!(matcher.first(&line), expected.first().copied());
+            prop_assert_eq!(matcher.last(&line), expected.last().copied());
+            prop_assert_eq!(matcher.digits(&line), expected);
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Day 2: Cube Conundrum. Games of cubes drawn from a bag.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{self, ParseError, Token};
+use crate::solution::{Answer, Parameters, Solution};
+
+/// The cubes shown at once, by colour.
+pub type Draw = BTreeMap<String, u32>;
+
+/// The cubes in a bag, by colour. A colour that isn't listed has none.
+pub type Bag = BTreeMap<String, u32>;
+
+/// One game: its id and the handfuls of cubes the elf showed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+/// How many cubes were shown in each draw of a game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DrawStats {
+    pub draws: usize,
+    pub fewest: u32,
+    pub most: u32,
+    pub total: u32,
+}
+
+impl Game {
+    /// Whether every draw could have come from `bag`.
+    pub fn is_feasible(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| {
+            draw.iter()
+                .all(|(color, &num)| num <= bag.get(color).copied().unwrap_or(0))
+        })
+    }
+
+    /// The fewest cubes of each colour that make the game possible.
+    pub fn min_bag(&self) -> Bag {
+        let mut bag = Bag::new();
+        for (color, &num) in self.draws.iter().flatten() {
+            let most = bag.entry(color.clone()).or_default();
+            *most = (*most).max(num);
+        }
+        bag
+    }
+
+    /// The product of the smallest bag's cube counts, over the colours the game shows.
+    pub fn power(&self) -> u64 {
+        self.min_bag().values().map(|&num| num as u64).product()
+    }
+
+    /// The fewest, most and total cubes shown per draw.
+    pub fn stats(&self) -> DrawStats {
+        let sizes = self.draws.iter().map(|draw| draw.values().sum::<u32>());
+        DrawStats {
+            draws: self.draws.len(),
+            fewest: sizes.clone().min().unwrap_or(0),
+            most: sizes.clone().max().unwrap_or(0),
+            total: sizes.sum(),
+        }
+    }
+}
+
+fn parse_game(line: Token) -> Result<Game, ParseError> {
+    let (game, draws) = line.split_once(": ")?;
+    let id = game.strip_prefix("Game ")?.parse()?;
+    let draws = draws
+        .split("; ")
+        .map(|draw| {
+            let mut cubes = Draw::new();
+            for pair in draw.split(", ") {
+                let [num, color] = pair.split_n(pair.split(" "))?;
+                *cubes.entry(color.text().to_string()).or_default() += num.parse::<u32>()?;
+            }
+            Ok(cubes)
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Game { id, draws })
+}
+
+/// Each game, with the colours it names.
+pub fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse::lines(Day02::DAY, input).map(parse_game).collect()
+}
+
+/// The bag of part 1: 12 red, 13 green and 14 blue cubes.
+pub fn puzzle_bag() -> Bag {
+    [("red", 12), ("green", 13), ("blue", 14)]
+        .into_iter()
+        .map(|(color, num)| (color.to_string(), num))
+        .collect()
+}
+
+/// A bag written like a draw, e.g. `12 red, 13 green, 14 blue`.
+pub fn parse_bag(text: &str) -> Result<Bag> {
+    let mut bag = Bag::new();
+    for pair in text
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+    {
+        let Some((num, color)) = pair.split_once(' ') else {
+            bail!("expected a number of cubes and a colour, got `{pair}`");
+        };
+        let num = num
+            .parse::<u32>()
+            .with_context(|| format!("reading the cubes in `{pair}`"))?;
+        *bag.entry(color.trim().to_string()).or_default() += num;
+    }
+    Ok(bag)
+}
+
+/// A bag from a TOML file of colours and counts, e.g. `red = 12`.
+pub fn read_bag(path: &Path) -> Result<Bag> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// The bag written like a draw, colours in alphabetical order.
+pub fn format_bag(bag: &Bag) -> String {
+    bag.iter()
+        .map(|(color, num)| format!("{num} {color}"))
+        .join(", ")
+}
+
+/// The smallest bag with which the games `chosen`, and no others, are possible. Any bag that
+/// works holds at least as many cubes of each colour, and then rules out no more games, so it is
+/// the only smallest one.
+pub fn smallest_bag(games: &[Game], chosen: &[u32]) -> Result<Bag> {
+    if let Some(id) = chosen
+        .iter()
+        .find(|&&id| games.iter().all(|game| game.id != id))
+    {
+        bail!("there is no game {id}");
+    }
+    let mut bag = Bag::new();
+    for game in games.iter().filter(|game| chosen.contains(&game.id)) {
+        for (color, num) in game.min_bag() {
+            let most = bag.entry(color).or_default();
+            *most = (*most).max(num);
+        }
+    }
+    if let Some(game) = games
+        .iter()
+        .find(|game| !chosen.contains(&game.id) && game.is_feasible(&bag))
+    {
+        bail!(
+            "game {} is possible with any bag that allows the chosen games",
+            game.id
+        );
+    }
+    Ok(bag)
+}
+
+/// The bag to test the games against in part 1, and the games `aoc report` finds the smallest
+/// bag for.
+#[derive(Clone, Debug)]
+pub struct Params {
+    pub bag: Bag,
+    pub games: Option<Vec<u32>>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { bag: puzzle_bag(), games: None }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "bag" => self.bag = parse_bag(value)?,
+            "bag_file" => self.bag = read_bag(Path::new(value))?,
+            "games" => {
+                let games = value.split(',').map(|id| id.trim().parse()).try_collect();
+                let games = games
+                    .with_context(|| format!("expected comma separated game ids, got `{value}`"))?;
+                self.games = Some(games);
+            }
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// The sum of the ids of the games possible with `bag`.
+pub fn part1(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_feasible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// The sum of the powers of the fewest cubes that make each game possible.
+pub fn part2(games: &[Game]) -> u64 {
+    games.iter().map(Game::power).sum()
+}
+
+/// `size` games of up to five draws of each colour.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for game in 1..=size {
+        let draws = (0..rng.gen_range(1..=5))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let colors = &colors[..rng.gen_range(1..=3)];
+                colors
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .join(", ")
+            })
+            .join("; ");
+        res.push_str(&format!("Game {game}: {draws}\n"));
+    }
+    res
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Game>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(part1(input, &params.bag).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    /// The smallest bag with which exactly the `games` are possible.
+    fn report(input: &Self::Input, params: &Params) -> Result<String> {
+        let Some(games) = &params.games else {
+            bail!("choose the games the bag must allow, e.g. `--param games=1,4,9`");
+        };
+        Ok(format_bag(&smallest_bag(input, games)?))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_bag, parse_bag, parse_input, part1, part2, puzzle_bag, smallest_bag, Bag, Day02,
+        DrawStats,
+    };
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::input::read_input;
+    use crate::solution::{Answer, AnySolution, Erased};
+
+    example_tests!(2023, Day02);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(
+            &parse_input(&read_input(2023, 2).unwrap()).unwrap(),
+            &puzzle_bag(),
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 2, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 2).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 2, 2));
+    }
+
+    #[test]
+    fn test_game() {
+        let games =
+            parse_input("Game 7: 3 teal, 2 red; 1 red, 4 teal, 1 red\nGame 12: 5 blue\n").unwrap();
+        assert_eq!(games.iter().map(|g| g.id).collect::<Vec<_>>(), [7, 12]);
+        let game = &games[0];
+        let bag: Bag = [("red".to_string(), 2), ("teal".to_string(), 4)].into();
+        assert_eq!(game.min_bag(), bag);
+        assert_eq!(game.power(), 8);
+        assert!(game.is_feasible(&bag));
+        assert!(!game.is_feasible(&puzzle_bag()));
+        assert!(games[1].is_feasible(&puzzle_bag()));
+        assert_eq!(
+            game.stats(),
+            DrawStats { draws: 2, fewest: 5, most: 6, total: 11 }
+        );
+        assert_eq!(part1(&games, &puzzle_bag()), 12);
+    }
+
+    #[test]
+    fn test_smallest_bag() {
+        let games =
+            parse_input("Game 1: 3 red, 1 blue\nGame 2: 1 red; 2 blue\nGame 3: 4 blue\n").unwrap();
+        let bag = smallest_bag(&games, &[1, 2]).unwrap();
+        assert_eq!(bag, parse_bag("3 red, 2 blue").unwrap());
+        assert_eq!(part1(&games, &bag), 3);
+        assert!(smallest_bag(&games, &[1, 3]).is_err());
+        assert!(smallest_bag(&games, &[4]).is_err());
+
+        let games = parse_input(&fixtures::input(2023, 2, "example1").unwrap()).unwrap();
+        let bag = smallest_bag(&games, &[1, 2, 5]).unwrap();
+        assert_eq!(format_bag(&bag), "6 blue, 3 green, 6 red");
+        assert!(parse_bag("12 red, green").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let day = Erased::<Day02>::new();
+        let games = day
+            .parse(&fixtures::input(2023, 2, "example1").unwrap())
+            .unwrap();
+        let params = [("games".to_string(), "1, 2,5".to_string())];
+        assert_eq!(
+            day.report(games.as_ref(), &params).unwrap(),
+            "6 blue, 3 green, 6 red"
+        );
+        assert!(day.report(games.as_ref(), &[]).is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("Game 1: 3 red\nGme 2: 1 blue\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "Gme 2"));
+    }
+}
@@ -0,0 +1,321 @@
+//! Day 3: Gear Ratios. Part numbers and gears in an engine schematic.
+
+use std::collections::{BTreeSet, HashMap};
+use std::iter;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Parameters, Solution};
+
+/// What sits in a run of cells of the schematic.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ElementType {
+    Number(u64),
+    Symbol(char),
+}
+
+/// A number or symbol and the cells it covers on one row.
+#[derive(Debug)]
+pub struct Element {
+    pub value: ElementType,
+    pub row: usize,
+    pub start_col: usize,
+    pub end_col: usize,
+}
+
+/// An element's index in the schematic's list of elements.
+pub type ElementId = usize;
+
+/// Every element, and the id of the element at each cell that holds one.
+pub type Schematic = (Vec<Element>, HashMap<(usize, usize), ElementId>);
+
+pub fn parse_schematic(input: &str) -> Result<Schematic, ParseError> {
+    let mut elements = Vec::new();
+    let mut locations = HashMap::new();
+
+    for (row, line) in parse::lines(Day03::DAY, input).enumerate() {
+        let mut number = 0u64;
+        let mut digits = 0;
+
+        let chars = line.chars().map(|(c, token)| (c, Some(token)));
+        for (col, (char, token)) in chars.chain(iter::once(('.', None))).enumerate() {
+            if let Some(c) = char.to_digit(10) {
+                number = number
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(c as u64))
+                    .ok_or_else(|| token.unwrap().error("part number is too large"))?;
+                digits += 1
+            } else {
+                if digits > 0 {
+                    let value = ElementType::Number(number);
+                    let start_col = col - digits;
+                    let end_col = col - 1;
+                    for c in start_col..(end_col + 1) {
+                        locations.insert((row, c), elements.len());
+                    }
+                    elements.push(Element { value, row, start_col, end_col });
+                    number = 0;
+                    digits = 0;
+                }
+                if char != '.' {
+                    let value = ElementType::Symbol(char);
+                    locations.insert((row, col), elements.len());
+                    elements.push(Element { value, row, start_col: col, end_col: col });
+                }
+            }
+        }
+    }
+
+    Ok((elements, locations))
+}
+
+/// Which symbols count, either all of them or only those listed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Symbols {
+    Any,
+    Only(Vec<char>),
+}
+
+impl Symbols {
+    pub fn contains(&self, symbol: char) -> bool {
+        match self {
+            Symbols::Any => true,
+            Symbols::Only(symbols) => symbols.contains(&symbol),
+        }
+    }
+}
+
+impl FromStr for Symbols {
+    type Err = anyhow::Error;
+
+    /// `any`, or the symbols themselves run together, e.g. `*#`.
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "any" {
+            return Ok(Symbols::Any);
+        }
+        if let Some(c) = s.chars().find(|c| c.is_ascii_digit() || *c == '.') {
+            bail!("`{c}` is not a symbol");
+        }
+        Ok(Symbols::Only(s.chars().collect()))
+    }
+}
+
+/// The ids of the elements touching `id`, diagonals included, in order.
+pub fn neighbours((elements, locations): &Schematic, id: ElementId) -> BTreeSet<ElementId> {
+    let e = &elements[id];
+    let mut res = BTreeSet::new();
+    for row in e.row.saturating_sub(1)..(e.row + 2) {
+        for col in e.start_col.saturating_sub(1)..(e.end_col + 2) {
+            if let Some(&adjacent) = locations.get(&(row, col)) {
+                res.insert(adjacent);
+            }
+        }
+    }
+    res.remove(&id);
+    res
+}
+
+/// The value of the element if it's a number.
+fn number(schematic: &Schematic, id: ElementId) -> Option<u64> {
+    match schematic.0[id].value {
+        ElementType::Number(v) => Some(v),
+        ElementType::Symbol(_) => None,
+    }
+}
+
+/// The ids of the numbers touching at least one symbol in `symbols`.
+pub fn numbers_next_to<'a>(
+    schematic: &'a Schematic,
+    symbols: &'a Symbols,
+) -> impl Iterator<Item = ElementId> + 'a {
+    (0..schematic.0.len()).filter(move |&id| {
+        number(schematic, id).is_some()
+            && neighbours(schematic, id).into_iter().any(|adj| {
+                matches!(schematic.0[adj].value, ElementType::Symbol(c) if symbols.contains(c))
+            })
+    })
+}
+
+/// Each symbol in `symbols` that touches exactly `count` numbers, with the ids of those numbers.
+/// Other symbols it touches don't matter.
+pub fn symbols_with_numbers<'a>(
+    schematic: &'a Schematic,
+    symbols: &'a Symbols,
+    count: usize,
+) -> impl Iterator<Item = (ElementId, Vec<ElementId>)> + 'a {
+    (0..schematic.0.len()).filter_map(move |id| {
+        let ElementType::Symbol(c) = schematic.0[id].value else {
+            return None;
+        };
+        let numbers = neighbours(schematic, id)
+            .into_iter()
+            .filter(|&adj| number(schematic, adj).is_some())
+            .collect_vec();
+        (symbols.contains(c) && numbers.len() == count).then_some((id, numbers))
+    })
+}
+
+/// The symbols that mark part numbers, and the gears: the symbols touching exactly `gear_numbers`
+/// numbers.
+#[derive(Clone, Debug)]
+pub struct Params {
+    pub part_symbols: Symbols,
+    pub gear_symbols: Symbols,
+    pub gear_numbers: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part_symbols: Symbols::Any,
+            gear_symbols: Symbols::Only(vec!['*']),
+            gear_numbers: 2,
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part_symbols" => self.part_symbols = value.parse()?,
+            "gear_symbols" => self.gear_symbols = value.parse()?,
+            "gear_numbers" => self.gear_numbers = value.parse()?,
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// The sum of the numbers next to a part symbol.
+pub fn part1(schematic: &Schematic, params: &Params) -> u64 {
+    numbers_next_to(schematic, &params.part_symbols)
+        .filter_map(|id| number(schematic, id))
+        .sum()
+}
+
+/// The sum of the gear ratios, the products of the numbers next to each gear. A gear that also
+/// touches another symbol still counts, as the puzzle only asks about its numbers.
+pub fn part2(schematic: &Schematic, params: &Params) -> u64 {
+    symbols_with_numbers(schematic, &params.gear_symbols, params.gear_numbers)
+        .map(|(_, numbers)| {
+            numbers
+                .into_iter()
+                .filter_map(|id| number(schematic, id))
+                .product::<u64>()
+        })
+        .sum()
+}
+
+/// A `size` by `size` schematic scattered with numbers and symbols.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let digits = rng.gen_range(1..=3);
+            if rng.gen_bool(0.15) && row.len() + digits < size {
+                // Numbers are always followed by a gap, so two never run together.
+                row.extend((0..digits).map(|_| rng.gen_range('0'..='9')));
+                row.push('.');
+            } else if rng.gen_bool(0.1) {
+                row.push(*b"*#+$/@%=&-".choose(rng).unwrap() as char);
+            } else {
+                row.push('.');
+            }
+        }
+        res.push_str(&row);
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Schematic;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_schematic(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(part1(input, params).into())
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer> {
+        Ok(part2(input, params).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        neighbours, numbers_next_to, parse_schematic, part1, part2, symbols_with_numbers, Day03,
+        Params, Symbols,
+    };
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day03);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(
+            &parse_schematic(&read_input(2023, 3).unwrap()).unwrap(),
+            &Params::default(),
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 3, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_schematic(&read_input(2023, 3).unwrap()).unwrap(),
+            &Params::default(),
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 3, 2));
+    }
+
+    #[test]
+    fn test_queries() {
+        let schematic = parse_schematic("12*12\n..#..\n7...8\n").unwrap();
+        assert_eq!(
+            neighbours(&schematic, 1).into_iter().collect::<Vec<_>>(),
+            [0, 2, 3]
+        );
+        let star = Symbols::Only(vec!['*']);
+        assert_eq!(
+            numbers_next_to(&schematic, &star).collect::<Vec<_>>(),
+            [0, 2]
+        );
+        assert_eq!(
+            numbers_next_to(&schematic, &Symbols::Any).collect::<Vec<_>>(),
+            [0, 2]
+        );
+        assert_eq!(
+            symbols_with_numbers(&schematic, &Symbols::Any, 2).collect::<Vec<_>>(),
+            [(1, vec![0, 2]), (3, vec![0, 2])]
+        );
+        assert_eq!(
+            "*#".parse::<Symbols>().unwrap(),
+            Symbols::Only(vec!['*', '#'])
+        );
+        assert!("*1".parse::<Symbols>().is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Day 4: Scratchcards. Cards that win copies of the cards below them.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+use std::iter;
+
+use crate::parse::{self, ParseError, Token};
+use crate::solution::{Answer, Solution};
+
+/// How many of each card's numbers are winning numbers.
+pub fn parse_winning_numbers(input: &str) -> Result<Vec<usize>, ParseError> {
+    fn parse_numbers(s: Token) -> Result<HashSet<u32>, ParseError> {
+        s.split_whitespace().map(|s| s.parse::<u32>()).collect()
+    }
+
+    parse::lines(Day04::DAY, input)
+        .map(|line| {
+            let (_, numbers) = line.split_once(": ")?;
+            let (winning_numbers, my_numbers) = numbers.split_once(" | ")?;
+            Ok(parse_numbers(winning_numbers)?
+                .intersection(&parse_numbers(my_numbers)?)
+                .count())
+        })
+        .collect()
+}
+
+/// The total points, doubling for each match after the first.
+pub fn part1(cards: &[usize]) -> u32 {
+    cards
+        .iter()
+        .copied()
+        .filter(|&num_winning_numbers| num_winning_numbers > 0)
+        .map(|num_winning_numbers| 2_u32.pow(num_winning_numbers as u32 - 1))
+        .sum()
+}
+
+/// The number of cards held once every won copy has been scratched.
+pub fn part2(cards: &[usize]) -> u32 {
+    let mut counts = iter::repeat(0).take(cards.len()).collect_vec();
+    let mut q = VecDeque::from((0..cards.len()).collect_vec());
+
+    while let Some(i) = q.pop_front() {
+        counts[i] += 1;
+        for j in (i + 1)..(i + cards[i] + 1) {
+            q.push_back(j);
+        }
+    }
+
+    counts.iter().sum()
+}
+
+/// `size` cards of 10 winning numbers and 25 numbers you have. Matches never run past the last
+/// card, and no card is won more than a thousand times so the copies stay countable.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const MAX_COPIES: usize = 1000;
+    let mut copies = vec![1; size];
+    let mut res = String::new();
+    for i in 0..size {
+        let mut matches = rng.gen_range(0..=(size - 1 - i).min(10));
+        if let Some(over) = (i + 1..=i + matches).find(|&j| copies[j] + copies[i] > MAX_COPIES) {
+            matches = over - i - 1;
+        }
+        for j in i + 1..=i + matches {
+            copies[j] += copies[i];
+        }
+
+        let mut numbers = (1..100).collect_vec();
+        numbers.shuffle(rng);
+        let winning = &numbers[..10];
+        let mut have = numbers[..matches]
+            .iter()
+            .chain(&numbers[10..35 - matches])
+            .collect_vec();
+        have.shuffle(rng);
+        let winning = winning.iter().map(|n| format!("{n:>2}")).join(" ");
+        let have = have.iter().map(|n| format!("{n:>2}")).join(" ");
+        res.push_str(&format!("Card {:>3}: {winning} | {have}\n", i + 1));
+    }
+    res
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Vec<usize>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_winning_numbers(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_winning_numbers, part1, part2, Day04};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day04);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_winning_numbers(&read_input(2023, 4).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 4, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_winning_numbers(&read_input(2023, 4).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 4, 2));
+    }
+}
@@ -0,0 +1,287 @@
+//! Day 5: If You Give A Seed A Fertilizer. Seeds mapped through the almanac to locations.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::VecDeque;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Convert the input to ranges (source_start, source_end, dest_start, dest_end) for convenience.
+///
+pub type Almanac = (Vec<u64>, Vec<Vec<(u64, u64, u64, u64)>>);
+
+pub fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = parse::lines(Day05::DAY, input);
+    let Some(first) = lines.next() else {
+        return Err(parse::end_of_input(Day05::DAY, input, "`seeds:`"));
+    };
+    let seeds = first
+        .strip_prefix("seeds:")?
+        .split_whitespace()
+        .map(|seed| seed.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut maps: Vec<Vec<_>> = Vec::new();
+    for line in lines {
+        if line.text().contains(":") {
+            maps.push(Vec::new());
+        } else if !line.is_empty() {
+            let [end, start, range] = line.split_n(line.split_whitespace())?;
+            let (end, start) = (end.parse::<u64>()?, start.parse::<u64>()?);
+            let length = match range.parse::<u64>()? {
+                0 => return Err(range.error("expected a positive range length")),
+                length => length,
+            };
+            let Some(map) = maps.last_mut() else {
+                return Err(line.error("expected a map header before the first range"));
+            };
+            map.push((start, start + length - 1, end, end + length - 1));
+        }
+    }
+
+    maps.iter_mut().for_each(|m| m.sort());
+    Ok((seeds, maps))
+}
+
+/// The lowest location of any seed.
+pub fn part1((seeds, maps): &Almanac) -> u64 {
+    let mut res = u64::MAX;
+
+    for &seed in seeds.iter() {
+        let location = map_seed(maps, seed);
+        res = res.min(location);
+    }
+    res
+}
+
+/// The location a seed ends up at after going through every map.
+pub fn map_seed(maps: &[Vec<(u64, u64, u64, u64)>], seed: u64) -> u64 {
+    let mut current = seed;
+    for map in maps.iter() {
+        let mut next = current;
+        for &(source_start, source_end, dest_start, _dest_end) in map.iter() {
+            if current >= source_start && current <= source_end {
+                next = dest_start + (current - source_start);
+                break;
+            }
+        }
+        current = next;
+    }
+    current
+}
+
+/// The lowest location of any seed when the seeds are read as ranges.
+pub fn part2((seeds, maps): &Almanac) -> u64 {
+    let mut locs = Vec::new();
+
+    // Operate on ranges rather than individual locations.
+    for (&seed_start, &seed_range) in seeds.iter().tuples() {
+        locs.push((seed_start, seed_start + seed_range - 1));
+    }
+
+    for level in 0..maps.len() {
+        // 1. Merge the ranges to collapse any overlap.
+        // 2. Map the previous locations to the next level locations.
+        let mut q = merge_ranges(&mut locs);
+        locs = map_ranges(&mut q, maps.get(level).unwrap());
+    }
+
+    locs.iter().map(|&(start, _end)| start).min().unwrap()
+}
+
+/// Sort the ranges and merge consecutive ranges that overlap.
+///
+fn merge_ranges(locs: &mut Vec<(u64, u64)>) -> VecDeque<(u64, u64)> {
+    locs.sort();
+
+    let mut current = None;
+    let mut q = VecDeque::new();
+    for &(start, end) in locs.iter() {
+        if let Some((current_start, current_end)) = current {
+            if start >= current_start && start <= current_end {
+                current = Some((current_start, end.max(current_end)))
+            } else {
+                q.push_back((current_start, current_end));
+                current = Some((start, end))
+            }
+        } else {
+            current = Some((start, end))
+        }
+    }
+    if let Some((start, end)) = current {
+        q.push_back((start, end))
+    }
+    q
+}
+
+fn map_ranges(
+    ranges: &mut VecDeque<(u64, u64)>,
+    map: &Vec<(u64, u64, u64, u64)>,
+) -> Vec<(u64, u64)> {
+    // There's probably a more clever way to do this with binary search so you don't have to
+    // scan the rules linearly and calculate messy overlap, but not going to bother. This is fast
+    // enough.
+    let mut next_locs = Vec::new();
+    while let Some((start, end)) = ranges.pop_front() {
+        let mut overlap_found = false;
+        for &(source_start, source_end, dest_start, _dest_end) in map.iter() {
+            let overlap_start = start.max(source_start);
+            let overlap_end = end.min(source_end);
+            if overlap_start <= overlap_end {
+                let new_start = dest_start + (overlap_start - source_start);
+                let new_end = dest_start + (overlap_end - source_start);
+                next_locs.push((new_start, new_end));
+
+                if start < overlap_start {
+                    ranges.push_front((start, overlap_start - 1));
+                }
+                if overlap_end < end {
+                    ranges.push_front((overlap_end + 1, end));
+                }
+                overlap_found = true;
+                break;
+            }
+        }
+        if !overlap_found {
+            next_locs.push((start, end))
+        }
+    }
+    next_locs
+}
+
+/// `size` seed ranges, and the seven maps each with `size` ranges that never overlap.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const SPAN: u64 = 1 << 32;
+    let size = size.max(1);
+    let step = SPAN / size as u64;
+
+    let seeds = (0..size)
+        .map(|_| format!("{} {}", rng.gen_range(0..SPAN), rng.gen_range(1..step)))
+        .join(" ");
+    let mut res = format!("seeds: {seeds}\n");
+    let names = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    for (from, to) in names.iter().tuple_windows() {
+        let mut start = 0;
+        let mut ranges = Vec::new();
+        for _ in 0..size {
+            start += rng.gen_range(0..step / 4);
+            let length = rng.gen_range(1..step / 2);
+            ranges.push(format!("{} {start} {length}", rng.gen_range(0..SPAN)));
+            start += length;
+        }
+        ranges.shuffle(rng);
+        res.push_str(&format!("\n{from}-to-{to} map:\n{}\n", ranges.join("\n")));
+    }
+    res
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Almanac;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use proptest::prelude::*;
+
+    use super::{map_ranges, map_seed, parse_input, part1, part2, Day05};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day05);
+
+    /// A map whose source ranges don't overlap, as in the puzzle, but may be adjacent.
+    fn map() -> impl Strategy<Value = Vec<(u64, u64, u64, u64)>> {
+        prop::collection::vec((0..4u64, 1..8u64, 0..60u64), 0..5).prop_map(|ranges| {
+            let mut start = 0;
+            let mut map = Vec::new();
+            for (gap, length, dest) in ranges {
+                start += gap;
+                map.push((start, start + length - 1, dest, dest + length - 1));
+                start += length;
+            }
+            map
+        })
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 5).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 5, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 5).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 5, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_map_ranges(map in map(), start in 0..40u64, length in 1..30u64) {
+            let end = start + length - 1;
+            let mut ranges = VecDeque::from([(start, end)]);
+            let mut mapped = map_ranges(&mut ranges, &map)
+                .into_iter()
+                .flat_map(|(start, end)| start..=end)
+                .collect::<Vec<_>>();
+            mapped.sort();
+            let maps = vec![map];
+            let mut expected = (start..=end).map(|seed| map_seed(&maps, seed)).collect::<Vec<_>>();
+            expected.sort();
+            prop_assert_eq!(mapped, expected);
+        }
+
+        #[test]
+        fn prop_part2(
+            seeds in prop::collection::vec((0..40u64, 1..10u64), 1..4),
+            maps in prop::collection::vec(map(), 1..4),
+        ) {
+            let lowest = seeds
+                .iter()
+                .flat_map(|&(start, length)| start..start + length)
+                .map(|seed| map_seed(&maps, seed))
+                .min();
+            let seeds = seeds.into_iter().flat_map(|(start, length)| [start, length]).collect();
+            prop_assert_eq!(Some(part2(&(seeds, maps))), lowest);
+        }
+    }
+}
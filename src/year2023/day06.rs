@@ -0,0 +1,178 @@
+//! Day 6: Wait For It. Boat races won by holding the button long enough.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{self, ParseError, Token};
+use crate::solution::{Answer, Solution};
+
+/// The values after "Time:" and "Distance:".
+///
+fn value_lines(input: &str) -> Result<(Token<'_>, Token<'_>), ParseError> {
+    let mut lines = parse::lines(Day06::DAY, input);
+    let mut next = |label: &str| match lines.next() {
+        Some(line) => line.strip_prefix(label),
+        None => Err(parse::end_of_input(
+            Day06::DAY,
+            input,
+            &format!("`{label}`"),
+        )),
+    };
+    Ok((next("Time:")?, next("Distance:")?))
+}
+
+/// Pair up the "Time:" and "Distance:" columns into (time, distance) races.
+///
+pub fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let (times, distances) = value_lines(input)?;
+    let parse_all = |values: Token| -> Result<Vec<u64>, ParseError> {
+        values
+            .split_whitespace()
+            .map(|v| v.parse::<u64>())
+            .collect()
+    };
+    let (times, distances) = (parse_all(times)?, parse_all(distances)?);
+    if times.len() != distances.len() {
+        let message = format!("expected {} distances, one per race", times.len());
+        return Err(value_lines(input)?.1.error(message));
+    }
+    Ok(times.into_iter().zip(distances).collect_vec())
+}
+
+/// Ignore the spaces between the columns and read each line as a single number.
+///
+pub fn parse_input2(input: &str) -> Result<(u64, u64), ParseError> {
+    let parse_joined = |values: Token| {
+        let joined = values.split_whitespace().map(|v| v.text()).join("");
+        joined
+            .parse::<u64>()
+            .map_err(|_| values.trim().error("expected a number"))
+    };
+    let (time, distance) = value_lines(input)?;
+    Ok((parse_joined(time)?, parse_joined(distance)?))
+}
+
+/// The product of the number of ways to win each race.
+pub fn part1(races: &[(u64, u64)]) -> u64 {
+    let mut res = 1;
+    for &(time, distance) in races.iter() {
+        let ways = solve_race(time, distance);
+        if ways > 0 {
+            res *= ways;
+        }
+    }
+    res
+}
+
+/// The number of ways to win the single long race.
+pub fn part2(time: u64, distance: u64) -> u64 {
+    return solve_race(time, distance);
+}
+
+/// The number of button hold times that go further than `distance` in `time`.
+pub fn solve_race(time: u64, distance: u64) -> u64 {
+    // Solve the inequality for t: t*(time - t) > d => -t^2 + time*t - d > 0
+    // Use quadratic equation to find the roots. The difference between the roots is the
+    // region where the inequality is true. If the roots are integers, those are the points where
+    // the equation exactly equals zero, so we need to nudge them a bit in the right direction.
+    let t = time as f64;
+    let d = distance as f64;
+    let mut r1 = (t - (t.powf(2.) - 4. * d).sqrt()) / 2.;
+    let mut r2 = (t + (t.powf(2.) - 4. * d).sqrt()) / 2.;
+    if r1.ceil() == r1 {
+        r1 += 0.01;
+    }
+    if r2.floor() == r2 {
+        r2 -= 0.01;
+    }
+    (r2.floor() - r1.ceil() + 1.) as u64
+}
+
+/// Up to four races, `size` if fewer, each winnable. Four keeps the joined race within a `u64`.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let races = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.gen_range(7..100);
+            (time, rng.gen_range(0..time * time / 4))
+        })
+        .collect_vec();
+    let times = races.iter().map(|(time, _)| format!("{time:>5}")).join("");
+    let distances = races.iter().map(|(_, d)| format!("{d:>5}")).join("");
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = (Vec<(u64, u64)>, (u64, u64));
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_input(input)?, parse_input2(input)?))
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(&input.0).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        let (time, distance) = input.1;
+        Ok(part2(time, distance).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{parse_input, parse_input2, part1, part2, solve_race, Day06};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day06);
+
+    /// Try holding the button for every possible time.
+    fn brute_force(time: u64, distance: u64) -> u64 {
+        (0..=time).filter(|t| t * (time - t) > distance).count() as u64
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 6).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 6, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (time, distance) = parse_input2(&read_input(2023, 6).unwrap()).unwrap();
+        let res = part2(time, distance);
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 6, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_race(
+            (time, distance) in (0..300u64).prop_flat_map(|time| (Just(time), 0..time * time / 4 + 2))
+        ) {
+            prop_assert_eq!(solve_race(time, distance), brute_force(time, distance));
+        }
+
+        #[test]
+        fn prop_solve_race_exact_roots(a in 0..1_000u64, b in 0..1_000u64) {
+            // Holding for a or b travels exactly the record, which doesn't beat it.
+            let (time, distance) = (a + b, a * b);
+            prop_assert_eq!(solve_race(time, distance), brute_force(time, distance));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Day 7: Camel Cards. Hands ranked by type and then card by card.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+struct Hand {
+    cards: Vec<u32>,
+    type_rank: u32,
+}
+
+fn cmp_hand(hand1: &Hand, hand2: &Hand) -> Ordering {
+    match hand1.type_rank.cmp(&hand2.type_rank) {
+        Ordering::Equal => hand1
+            .cards
+            .iter()
+            .zip(&hand2.cards)
+            .find_map(|(&c1, c2)| match c1.cmp(c2) {
+                Ordering::Equal => None,
+                o => Some(o),
+            })
+            .unwrap(),
+        o => o,
+    }
+}
+
+fn type_rank(cards: &Vec<u32>) -> u32 {
+    let mut counts = [0u32; 14];
+    for &c in cards {
+        counts[c as usize] += 1;
+    }
+    if counts[0] > 0 {
+        // Jokers
+        if let Some(i) = counts.iter().dropping(1).position_max() {
+            counts[i + 1] += counts[0];
+            counts[0] = 0
+        }
+    }
+    // Select top two card counts.
+    match counts
+        .into_iter()
+        .dropping(1)
+        .sorted()
+        .rev()
+        .take(2)
+        .collect_tuple()
+        .unwrap()
+    {
+        (5, 0) => 7,
+        (4, 1) => 6,
+        (3, 2) => 5,
+        (3, 1) => 4,
+        (2, 2) => 3,
+        (2, 1) => 2,
+        _ => 1,
+    }
+}
+
+/// Each hand as written, and its bid.
+pub fn parse_input(input: &str) -> Result<Vec<(String, u64)>, ParseError> {
+    parse::lines(Day07::DAY, input)
+        .map(|line| {
+            let (cards, bid) = line.split_once(" ")?;
+            for (c, card) in cards.chars() {
+                if !"AKQJT98765432".contains(c) {
+                    return Err(card.error("expected a card from `AKQJT98765432`"));
+                }
+            }
+            if cards.text().len() != 5 {
+                return Err(cards.error("expected a hand of 5 cards"));
+            }
+            Ok((cards.text().to_string(), bid.parse::<u64>()?))
+        })
+        .collect()
+}
+
+fn to_hands(input: &[(String, u64)], jokers: bool) -> Vec<(Hand, u64)> {
+    input
+        .iter()
+        .map(|(cards, bid)| {
+            let cards = cards
+                .chars()
+                .map(|c| match c {
+                    'A' => 13,
+                    'K' => 12,
+                    'Q' => 11,
+                    'J' => {
+                        if jokers {
+                            0
+                        } else {
+                            10
+                        }
+                    }
+                    'T' => 9,
+                    d => d.to_digit(10).unwrap() - 1,
+                })
+                .collect_vec();
+
+            let type_rank = type_rank(&cards);
+            (Hand { cards, type_rank }, *bid)
+        })
+        .collect_vec()
+}
+
+fn score_hands(hands: &mut Vec<(Hand, u64)>) -> u64 {
+    hands
+        .iter()
+        .sorted_by(|(h1, _), (h2, _)| cmp_hand(h1, h2))
+        .enumerate()
+        .map(|(r, (_hand, bid))| (r as u64 + 1) * *bid)
+        .sum()
+}
+
+/// The total winnings, each bid times its hand's rank.
+pub fn part1(input: &[(String, u64)]) -> u64 {
+    score_hands(&mut to_hands(input, false))
+}
+
+/// The total winnings with `J` as a joker that stands in for the best card.
+pub fn part2(input: &[(String, u64)]) -> u64 {
+    score_hands(&mut to_hands(input, true))
+}
+
+/// `size` different hands, each with a bid.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let cards = b"AKQJT98765432";
+    let mut hands = HashSet::new();
+    let mut res = String::new();
+    while hands.len() < size {
+        let hand: String = (0..5)
+            .map(|_| *cards.choose(rng).unwrap() as char)
+            .collect();
+        if hands.insert(hand.clone()) {
+            res.push_str(&format!("{hand} {}\n", rng.gen_range(1..1000)));
+        }
+    }
+    res
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<(String, u64)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day07};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day07);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 7).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 7, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 7).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 7, 2));
+    }
+}
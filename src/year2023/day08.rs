@@ -0,0 +1,269 @@
+//! Day 8: Haunted Wasteland. Following left and right turns through a network of nodes.
+
+use crate::cycle::{self, Cycle, History};
+use crate::gen;
+use crate::graphviz::{Dot, ToDot};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// The left and right instructions, and the left and right node out of each node.
+pub type Network = (Vec<char>, HashMap<String, (String, String)>);
+
+pub fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let mut instructions = Vec::new();
+    let mut nodes = HashMap::new();
+    let mut targets = Vec::new();
+    for line in parse::lines(Day08::DAY, input) {
+        if instructions.is_empty() {
+            for (c, token) in line.chars() {
+                if c != 'L' && c != 'R' {
+                    return Err(token.error("expected `L` or `R`"));
+                }
+                instructions.push(c);
+            }
+        } else if !line.is_empty() {
+            let (node, pairs) = line.split_once(" = ")?;
+            let pairs = pairs.strip_prefix("(")?.strip_suffix(")")?;
+            let (n1, n2) = pairs.split_once(", ")?;
+            nodes.insert(
+                node.text().to_string(),
+                (n1.text().to_string(), n2.text().to_string()),
+            );
+            targets.extend([n1, n2]);
+        }
+    }
+    if instructions.is_empty() {
+        return Err(parse::end_of_input(Day08::DAY, input, "instructions"));
+    }
+    if let Some(target) = targets.iter().find(|t| !nodes.contains_key(t.text())) {
+        return Err(target.error("expected a node defined in the network"));
+    }
+    Ok((instructions, nodes))
+}
+
+/// The steps from `AAA` to `ZZZ`.
+pub fn part1((instructions, nodes): &Network) -> Result<u64> {
+    let node = "AAA".to_string();
+    if !nodes.contains_key(&node) {
+        bail!("there is no node `{node}` to start from");
+    }
+    steps_to_z(&node, instructions, nodes)
+}
+
+/// An error if the walk from `n` never reaches a node ending in Z: once it has taken a step
+/// from every node at every instruction, it is going round in circles.
+fn steps_to_z(
+    n: &String,
+    instructions: &Vec<char>,
+    nodes: &HashMap<String, (String, String)>,
+) -> Result<u64> {
+    let limit = nodes.len() * instructions.len();
+    let mut node = n;
+    let mut count = 0;
+    for &c in instructions.iter().cycle() {
+        if count == limit {
+            bail!("the walk from `{n}` never reaches a node ending in Z");
+        }
+        let (left, right) = nodes.get(node).unwrap();
+        match c {
+            'L' => node = left,
+            'R' => node = right,
+            _ => unreachable!("parse_input only accepts L and R"),
+        }
+        count += 1;
+
+        if node.ends_with("Z") {
+            break;
+        }
+    }
+    Ok(count as u64)
+}
+
+/// The cycle in a ghost's walk from `start`, as (node, instruction index) states, and the steps
+/// in its first cycle at which it is on a node ending in Z.
+fn ghost(
+    start: &String,
+    instructions: &[char],
+    nodes: &HashMap<String, (String, String)>,
+) -> (Cycle, Vec<usize>) {
+    let history = History::find((start, 0), |&(node, i)| {
+        let (left, right) = nodes.get(node).unwrap();
+        let next = if instructions[i] == 'L' { left } else { right };
+        (next, (i + 1) % instructions.len())
+    });
+    let hits = history.positions(|(node, _)| node.ends_with('Z'));
+    (history.cycle, hits)
+}
+
+/// `None` if the ghosts are never all on nodes ending in Z at once, or not within `u64::MAX`
+/// steps.
+pub fn part2((instructions, nodes): &Network) -> Option<u64> {
+    let ghosts = nodes
+        .keys()
+        .filter(|&k| k.ends_with('A'))
+        .map(|node| ghost(node, instructions, nodes))
+        .collect::<Vec<_>>();
+    cycle::first_common(&ghosts, 1)
+}
+
+/// Each node's left and right turns, with the nodes ending in A shaded green and those ending in
+/// Z red.
+impl ToDot for Network {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::digraph();
+        for (node, (left, right)) in self.1.iter().sorted() {
+            if node.ends_with('A') {
+                dot.node(node, &[("style", "filled"), ("fillcolor", "palegreen")]);
+            } else if node.ends_with('Z') {
+                dot.node(node, &[("style", "filled"), ("fillcolor", "lightcoral")]);
+            }
+            if left == right {
+                dot.edge(node, left, &[("label", "LR")]);
+            } else {
+                dot.edge(node, left, &[("label", "L")]);
+                dot.edge(node, right, &[("label", "R")]);
+            }
+        }
+        dot
+    }
+}
+
+/// Instructions about `size` long and up to six ghosts. Each ghost walks a chain of layers from
+/// its `..A` node to its `..Z` node and then round again from the first layer, whichever way it
+/// turns, so every ghost reaches Z and they all meet there in the end.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let instructions: String = (0..rng.gen_range(size..=2 * size))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let ghosts = size.min(6);
+    let lengths = (0..ghosts).map(|_| rng.gen_range(2..20)).collect_vec();
+    let upper = |name: &String| name.to_uppercase();
+    let starts = gen::names(rng, ghosts, 2, &["aa"]);
+    let ends = gen::names(rng, ghosts, 2, &["zz"]);
+    let middle = gen::names(rng, lengths.iter().map(|m| 2 * (m - 1)).sum(), 2, &[]);
+    let middle = middle
+        .iter()
+        .map(|name| format!("{}{}", upper(name), rng.gen_range('B'..='Y')))
+        .collect_vec();
+    let mut middle = middle.into_iter();
+
+    let mut lines = Vec::new();
+    for (g, &length) in lengths.iter().enumerate() {
+        let (start, end) = match g {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (upper(&starts[g]) + "A", upper(&ends[g]) + "Z"),
+        };
+        let mut layers = vec![vec![start]];
+        layers.extend((1..length).map(|_| middle.by_ref().take(2).collect_vec()));
+        layers.push(vec![end]);
+        layers.push(layers[1].clone());
+        for (layer, next) in layers.iter().tuple_windows() {
+            for node in layer {
+                let left = next.choose(rng).unwrap();
+                let right = next.choose(rng).unwrap();
+                lines.push(format!("{node} = ({left}, {right})"));
+            }
+        }
+    }
+    lines.shuffle(rng);
+    format!("{instructions}\n\n{}\n", lines.join("\n"))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Network;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        part2(input)
+            .map(Answer::from)
+            .ok_or_else(|| anyhow!("the ghosts never all reach a node ending in Z"))
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(input.to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day08};
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::graphviz::ToDot;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day08);
+
+    #[test]
+    fn test_dot() {
+        let dot = parse_input(&fixtures::input(2023, 8, "example1").unwrap())
+            .unwrap()
+            .to_dot()
+            .to_string();
+        assert_eq!(
+            dot,
+            "digraph G {
+    AAA [style=filled, fillcolor=palegreen]
+    AAA -> BBB [label=LR]
+    BBB -> AAA [label=L]
+    BBB -> ZZZ [label=R]
+    ZZZ [style=filled, fillcolor=lightcoral]
+    ZZZ -> ZZZ [label=LR]
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_part1_errors() {
+        let no_start = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(
+            part1(&no_start).unwrap_err().to_string(),
+            "there is no node `AAA` to start from"
+        );
+        let no_end =
+            parse_input("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(
+            part1(&no_end).unwrap_err().to_string(),
+            "the walk from `AAA` never reaches a node ending in Z"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 8).unwrap()).unwrap()).unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 8, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 8).unwrap()).unwrap()).unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 8, 2));
+    }
+}
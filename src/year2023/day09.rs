@@ -0,0 +1,137 @@
+//! Day 9: Mirage Maintenance. Extrapolating histories by their differences.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse::lines(Day09::DAY, input)
+        .map(|l| {
+            if l.trim().is_empty() {
+                return Err(l.error("expected at least one value"));
+            }
+            l.split_whitespace().map(|v| v.parse::<i64>()).collect()
+        })
+        .collect()
+}
+
+/// The value after the end of the history.
+pub fn next_value(v: Vec<i64>) -> i64 {
+    seq_differences(v)
+        .iter()
+        .rev()
+        .map(|v| v.last().unwrap())
+        .sum()
+}
+
+/// The value before the start of the history.
+pub fn previous_value(v: Vec<i64>) -> i64 {
+    seq_differences(v)
+        .iter()
+        .rev()
+        .map(|v| v.first().unwrap())
+        .fold(0, |curr, &n| n - curr)
+}
+
+fn seq_differences(v: Vec<i64>) -> Vec<Vec<i64>> {
+    let mut seqs = Vec::new();
+    seqs.push(v);
+
+    while seqs.last().unwrap().iter().all_equal_value().is_err() {
+        let new_seq = seqs
+            .last()
+            .unwrap()
+            .iter()
+            .tuple_windows()
+            .map(|(&v1, &v2)| v2 - v1)
+            .collect_vec();
+        seqs.push(new_seq);
+    }
+    seqs
+}
+
+/// The sum of the next values.
+pub fn part1(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|v| next_value(v.clone())).sum()
+}
+
+/// The sum of the previous values.
+pub fn part2(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|v| previous_value(v.clone())).sum()
+}
+
+/// `size` histories of 21 values, each a polynomial of degree at most five.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        // Build up from constant differences by summing, once per degree.
+        let mut values = vec![rng.gen_range(-5..=5); 21];
+        for _ in 0..rng.gen_range(0..=5) {
+            let mut sum = rng.gen_range(-20..=20);
+            values = values
+                .iter()
+                .map(|&v| {
+                    let value = sum;
+                    sum += v;
+                    value
+                })
+                .collect();
+        }
+        res.push_str(&values.iter().join(" "));
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Vec<i64>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day09};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day09);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 9).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 9, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 9).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 9, 2));
+    }
+}
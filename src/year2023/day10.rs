@@ -0,0 +1,425 @@
+//! Day 10: Pipe Maze. The loop of pipes through the start and the tiles it encloses.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Parameters, Solution};
+use crate::viz::{self, Frame};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::parse(Day10::DAY, input, "|-LJ7F.S")?;
+    let starts = grid
+        .iter()
+        .filter(|(_, &c)| c == 'S')
+        .map(|(pos, _)| pos)
+        .collect_vec();
+    if starts.is_empty() {
+        return Err(parse::end_of_input(Day10::DAY, input, "a start tile `S`"));
+    }
+    if let Some(&pos) = starts.get(1) {
+        return Err(grid.error(Day10::DAY, pos, "expected a single start tile"));
+    }
+    Ok(grid)
+}
+
+fn targets_for_pipe(c: char, pos: Pos) -> Option<[Pos; 2]> {
+    let [a, b] = match c {
+        '|' => [Dir::South, Dir::North],
+        '-' => [Dir::East, Dir::West],
+        'L' => [Dir::East, Dir::North],
+        'J' => [Dir::West, Dir::North],
+        '7' => [Dir::South, Dir::West],
+        'F' => [Dir::East, Dir::South], // Clockwise is first choice
+        _ => return None,
+    };
+    Some([pos.step(a), pos.step(b)])
+}
+
+/// The tiles of the loop, in order from the start. An error points at where the loop breaks:
+/// a pipe leading off the map, or to a tile that doesn't lead back.
+pub fn find_loop(
+    grid: &Grid<char>,
+    start_loc: Pos,
+    start_char: char,
+) -> Result<Vec<Pos>, ParseError> {
+    let Some([first, last]) = targets_for_pipe(start_char, start_loc) else {
+        return Err(grid.error(
+            Day10::DAY,
+            start_loc,
+            format!("`{start_char}` is not a pipe"),
+        ));
+    };
+
+    // Travel around the loop until you return to the same coord.
+    let mut res = vec![start_loc];
+    let mut prior_loc = start_loc;
+    let mut current_loc = first;
+    while current_loc != start_loc {
+        let Some(&current_char) = grid.get(current_loc) else {
+            return Err(grid.error(
+                Day10::DAY,
+                prior_loc,
+                "expected a pipe that stays on the map",
+            ));
+        };
+        let next_loc = match targets_for_pipe(current_char, current_loc) {
+            Some([a, b]) if a == prior_loc => b,
+            Some([a, b]) if b == prior_loc => a,
+            _ => {
+                return Err(grid.error(
+                    Day10::DAY,
+                    current_loc,
+                    "expected a pipe continuing the loop",
+                ))
+            }
+        };
+        res.push(current_loc);
+        prior_loc = current_loc;
+        current_loc = next_loc;
+    }
+    if prior_loc != last {
+        return Err(grid.error(
+            Day10::DAY,
+            start_loc,
+            "expected the loop to close at the start",
+        ));
+    }
+
+    Ok(res)
+}
+
+/// Where the 'S' is.
+pub fn find_start_loc(grid: &Grid<char>) -> Pos {
+    grid.find(|&c| c == 'S').unwrap()
+}
+
+/// Work out which pipe is hidden under the 'S' from the neighbours that connect back to it.
+pub fn infer_start_char(map: &Grid<char>) -> Result<char, ParseError> {
+    let start = find_start_loc(map);
+    let connects =
+        |dir: Dir, pipes: &str| map.get(start.step(dir)).is_some_and(|&c| pipes.contains(c));
+    let north = connects(Dir::North, "|7F");
+    let south = connects(Dir::South, "|LJ");
+    let west = connects(Dir::West, "-LF");
+    let east = connects(Dir::East, "-J7");
+    match (north, south, west, east) {
+        (true, true, _, _) => Ok('|'),
+        (_, _, true, true) => Ok('-'),
+        (true, _, _, true) => Ok('L'),
+        (true, _, true, _) => Ok('J'),
+        (_, true, true, _) => Ok('7'),
+        (_, true, _, true) => Ok('F'),
+        _ => Err(map.error(
+            Day10::DAY,
+            start,
+            "expected two pipes connecting to the start",
+        )),
+    }
+}
+
+/// The steps to the point of the loop furthest from the start.
+pub fn part1(map: &Grid<char>, start_char: char) -> Result<u64, ParseError> {
+    let start_loc = find_start_loc(map);
+    Ok(find_loop(map, start_loc, start_char)?.len() as u64 / 2)
+}
+
+/// The loop, clockwise from its top-left corner, and the tiles it encloses.
+pub fn loop_and_inside(
+    map: &Grid<char>,
+    start_char: char,
+) -> Result<(Vec<Pos>, Vec<Pos>), ParseError> {
+    let mut map = map.clone();
+    let start_loc = find_start_loc(&map);
+    let loop_from_start = find_loop(&map, start_loc, start_char)?;
+    map[start_loc] = start_char; // Replace start characters
+
+    // Find the upper-left 'F' in the loop and move clockwise around the loop.
+    let top_left = *loop_from_start.iter().min().unwrap_or(&start_loc);
+    let loop_from_top_left = find_loop(&map, top_left, 'F')?;
+
+    let mut dir = 'N';
+    let mut west_inside = HashMap::new();
+
+    // Walk around the loops and record whether a point just west of this tile is inside the loop.
+    for &pos in loop_from_top_left.iter() {
+        let char = map[pos];
+        let (new_dir, west_inside_val) = match (char, dir) {
+            ('F', 'W') => ('S', true),
+            ('F', 'N') => ('E', false),
+            ('J', 'S') => ('W', true),
+            ('J', 'E') => ('N', true),
+            ('L', 'S') => ('E', true),
+            ('L', 'W') => ('N', false),
+            ('7', 'E') => ('S', true),
+            ('7', 'N') => ('W', false),
+            ('|', 'S') => (dir, true),
+            ('|', 'N') => (dir, false),
+            ('-', _) => (dir, true),
+            _ => return Err(map.error(Day10::DAY, pos, "expected a pipe continuing the loop")),
+        };
+        dir = new_dir;
+        west_inside.insert(pos, west_inside_val);
+    }
+
+    // Draw a ray from each non-loop tile toward the east and find the first loop segment on the
+    // same row. If just west of the loop tile is inside the loop, the non-loop tile is inside the
+    // loop.
+    let width = map.width() as i64;
+    let inside = map
+        .positions()
+        .filter(|pos| !west_inside.contains_key(pos))
+        .filter(|pos| {
+            ((pos.col + 1)..width)
+                .find_map(|col| west_inside.get(&Pos::new(pos.row, col)).copied())
+                .unwrap_or(false)
+        })
+        .collect();
+    Ok((loop_from_top_left, inside))
+}
+
+/// The number of tiles inside the loop.
+pub fn part2(map: &Grid<char>, start_char: char) -> Result<usize, ParseError> {
+    Ok(loop_and_inside(map, start_char)?.1.len())
+}
+
+/// The pipes in grey, the loop shaded clockwise from its top-left corner, and the tiles inside
+/// it in green.
+fn visualise(map: &Grid<char>, start_char: char) -> Result<Frame, ParseError> {
+    let (pipe_loop, inside) = loop_and_inside(map, start_char)?;
+    let mut frame = Frame::new(map, |&c| if c == '.' { viz::BLACK } else { viz::GREY });
+    frame
+        .path(&pipe_loop, viz::BLUE, viz::WHITE)
+        .highlight(inside, viz::GREEN);
+    Ok(frame)
+}
+
+/// The pipe hidden under the 'S'. Worked out from the neighbouring pipes when not given.
+#[derive(Default)]
+pub struct Params {
+    pub start_char: Option<char>,
+}
+
+impl Params {
+    fn start_char(&self, map: &Grid<char>) -> Result<char, ParseError> {
+        self.start_char.map_or_else(|| infer_start_char(map), Ok)
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "start_char" => self.start_char = Some(value.parse()?),
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// A loop around a random blob of up to `size` by `size` cells, drawn at twice the scale so it
+/// winds between tiles, among ground and stray pipes. The blob has no holes and no cells that
+/// touch only at a corner, so its outline is a single loop.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let k = size.max(1) as i64;
+    // A border of empty cells keeps the outline off the edge of the map.
+    let mut blob = Grid::new(k as usize + 2, k as usize + 2, false);
+    blob[Pos::new(k / 2 + 1, k / 2 + 1)] = true;
+    for _ in 0..2 * k * k {
+        let pos = Pos::new(rng.gen_range(1..=k), rng.gen_range(1..=k));
+        if blob[pos] || !blob.neighbours4(pos).any(|p| blob[p]) {
+            continue;
+        }
+        blob[pos] = true;
+        if pinched(&blob, pos) || has_hole(&blob) {
+            blob[pos] = false;
+        }
+    }
+
+    // Cell (i, j) of the blob covers tiles (2i..=2i+2, 2j..=2j+2), and the loop runs between
+    // tiles wherever the cells either side of it differ.
+    let inside = |i: i64, j: i64| blob.get(Pos::new(i, j)).copied().unwrap_or(false);
+    let east = |r: i64, c: i64| r % 2 == 0 && inside(r / 2 - 1, c / 2) != inside(r / 2, c / 2);
+    let south = |r: i64, c: i64| c % 2 == 0 && inside(r / 2, c / 2 - 1) != inside(r / 2, c / 2);
+    let side = 2 * k as usize + 5;
+    let mut map = Grid::new(side, side, '.');
+    let mut pipe_loop = Vec::new();
+    for pos in map.positions() {
+        let (r, c) = (pos.row, pos.col);
+        let pipe = match (south(r - 1, c), south(r, c), east(r, c - 1), east(r, c)) {
+            (true, true, false, false) => Some('|'),
+            (false, false, true, true) => Some('-'),
+            (true, false, false, true) => Some('L'),
+            (true, false, true, false) => Some('J'),
+            (false, true, true, false) => Some('7'),
+            (false, true, false, true) => Some('F'),
+            _ => None,
+        };
+        match pipe {
+            Some(pipe) => {
+                map[pos] = pipe;
+                pipe_loop.push(pos);
+            }
+            None if rng.gen_bool(0.25) => map[pos] = *b"|-LJ7F".choose(rng).unwrap() as char,
+            None => {}
+        }
+    }
+
+    let start = *pipe_loop.choose(rng).unwrap();
+    map[start] = 'S';
+    // Stray pipes beside the start would hide which way the loop leaves it.
+    for pos in map.neighbours4(start).collect_vec() {
+        if !pipe_loop.contains(&pos) {
+            map[pos] = '.';
+        }
+    }
+    map.to_string()
+}
+
+/// Whether the cell at `pos` touches another only at a corner.
+fn pinched(blob: &Grid<bool>, pos: Pos) -> bool {
+    [(-1, -1), (-1, 0), (0, -1), (0, 0)]
+        .iter()
+        .any(|&(dr, dc)| {
+            let at = |r, c| blob[Pos::new(pos.row + dr + r, pos.col + dc + c)];
+            (at(0, 0) && at(1, 1) && !at(0, 1) && !at(1, 0))
+                || (at(0, 1) && at(1, 0) && !at(0, 0) && !at(1, 1))
+        })
+}
+
+/// Whether any empty cell is cut off from the edge.
+fn has_hole(blob: &Grid<bool>) -> bool {
+    let mut seen = HashSet::from([Pos::new(0, 0)]);
+    let mut q = vec![Pos::new(0, 0)];
+    while let Some(pos) = q.pop() {
+        for next in blob.neighbours4(pos) {
+            if !blob[next] && seen.insert(next) {
+                q.push(next);
+            }
+        }
+    }
+    seen.len() < blob.iter().filter(|(_, &cell)| !cell).count()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Grid<char>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part1(input, params.start_char(input)?)?.into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part2(input, params.start_char(input)?)?.into())
+    }
+
+    fn visualise(input: &Self::Input, params: &Self::Params) -> Result<Vec<Frame>> {
+        Ok(vec![visualise(input, params.start_char(input)?)?])
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use super::{
+        find_loop, find_start_loc, generate, infer_start_char, parse_input, part1, part2, Day10,
+    };
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::gen;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day10);
+
+    #[test]
+    fn test_infer_start_char() {
+        for (example, start_char) in [("example1", 'F'), ("example5", '7')] {
+            let map = parse_input(&fixtures::input(2023, 10, example).unwrap()).unwrap();
+            assert_eq!(infer_start_char(&map).unwrap(), start_char);
+        }
+        assert_eq!(
+            infer_start_char(&parse_input(&read_input(2023, 10).unwrap()).unwrap()).unwrap(),
+            'J'
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 10).unwrap()).unwrap(), 'J').unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 10, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 10).unwrap()).unwrap(), 'J').unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 10, 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("S-7\n|.|\nL-\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 1, "L-"));
+        let err = parse_input("S-7\n|.|\nL-S\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 3, "S"));
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        let error = |map: &str, start_char: char| {
+            let err = part2(&parse_input(map).unwrap(), start_char).unwrap_err();
+            (err.line, err.column, err.token)
+        };
+        // The loop is broken by ground.
+        assert_eq!(error("S-7\n|.|\nL.J\n", 'F'), (3, 2, ".".to_string()));
+        // A pipe leads off the map.
+        assert_eq!(error("S-7\n|.|\nL-|\n", 'F'), (3, 3, "|".to_string()));
+        // The loop never comes back to the start.
+        assert_eq!(error("S-7\n|.|\nL-J\n", '-'), (1, 1, "S".to_string()));
+        assert_eq!(error("S-7\n|.|\nL-J\n", '.'), (1, 1, "S".to_string()));
+
+        let err = infer_start_char(&parse_input(".S.\n...\n").unwrap()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+    }
+
+    /// The tiles inside a loop by Pick's theorem, from its area and the tiles along it.
+    fn picks(map: &str) -> usize {
+        let map = parse_input(map).unwrap();
+        let pipe_loop =
+            find_loop(&map, find_start_loc(&map), infer_start_char(&map).unwrap()).unwrap();
+        let twice_area: i64 = pipe_loop
+            .iter()
+            .circular_tuple_windows()
+            .map(|(a, b)| a.row * b.col - b.row * a.col)
+            .sum();
+        (twice_area.unsigned_abs() as usize - pipe_loop.len()) / 2 + 1
+    }
+
+    proptest! {
+        #[test]
+        fn prop_part2(map in gen::strategy(generate, 1..12)) {
+            let start_char = infer_start_char(&parse_input(&map).unwrap()).unwrap();
+            prop_assert_eq!(part2(&parse_input(&map).unwrap(), start_char).unwrap(), picks(&map));
+        }
+    }
+}
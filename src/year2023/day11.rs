@@ -0,0 +1,156 @@
+//! Day 11: Cosmic Expansion. Distances between galaxies in an expanding universe.
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Parameters, Solution};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(Day11::DAY, input, ".#")
+}
+
+/// The galaxies' positions once each empty row and column is `multiplier` wide.
+pub fn expand(grid: &Grid<char>, multiplier: i64) -> Vec<Pos> {
+    let empty_rows: HashSet<usize> = (0..grid.height())
+        .filter(|&r| grid.row(r).iter().all(|&c| c == '.'))
+        .collect();
+    let empty_cols: HashSet<usize> = (0..grid.width())
+        .filter(|&c| grid.column(c).all(|&c| c == '.'))
+        .collect();
+
+    let mut res = Vec::new();
+    let mut row = 0;
+    for (i, line) in grid.rows().enumerate() {
+        let mut col = 0;
+        for (j, &c) in line.iter().enumerate() {
+            if c == '#' {
+                res.push(Pos::new(row, col));
+            }
+
+            if empty_cols.contains(&j) {
+                col += multiplier;
+            } else {
+                col += 1;
+            }
+        }
+
+        if empty_rows.contains(&i) {
+            row += multiplier;
+        } else {
+            row += 1;
+        }
+    }
+    res
+}
+
+/// The sum of the Manhattan distances between every pair of galaxies.
+pub fn sum_of_distances(galaxies: Vec<Pos>) -> i64 {
+    let mut res = 0;
+    for (i, &g1) in galaxies.iter().enumerate() {
+        for &g2 in galaxies.iter().dropping(i + 1) {
+            res += g1.manhattan(g2);
+        }
+    }
+
+    res
+}
+
+/// The sum of distances with empty rows and columns doubled.
+pub fn part1(grid: &Grid<char>) -> i64 {
+    let galaxies = expand(grid, 2);
+    sum_of_distances(galaxies)
+}
+
+/// The sum of distances with empty rows and columns `multiplier` times as wide.
+pub fn part2(grid: &Grid<char>, multiplier: i64) -> i64 {
+    let galaxies = expand(grid, multiplier);
+    sum_of_distances(galaxies)
+}
+
+/// How many rows or columns each empty row or column expands into for part 2.
+pub struct Params {
+    pub multiplier: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { multiplier: 1_000_000 }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "multiplier" => self.multiplier = value.parse()?,
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// A `size` by `size` image with a galaxy in about one cell in twenty.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        res.extend((0..size).map(|_| if rng.gen_bool(0.05) { '#' } else { '.' }));
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Grid<char>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part2(input, params.multiplier).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day11};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day11);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 11).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 11, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_input(&read_input(2023, 11).unwrap()).unwrap(),
+            1_000_000,
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 11, 2));
+    }
+}
@@ -0,0 +1,251 @@
+//! Day 12: Hot Springs. Arrangements of damaged springs that fit the groups.
+
+use std::collections::HashMap;
+use std::iter;
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Each row of springs and its damaged group sizes.
+pub fn parse_input(input: &str) -> Result<Vec<(String, Vec<usize>)>, ParseError> {
+    parse::lines(Day12::DAY, input)
+        .map(|line| {
+            let (line, groups) = line.split_once(" ")?;
+            if let Some((_, spring)) = line.chars().find(|&(c, _)| !".#?".contains(c)) {
+                return Err(spring.error("expected one of `.#?`"));
+            }
+            let groups = groups
+                .split(",")
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((line.text().to_string(), groups))
+        })
+        .collect()
+}
+
+/// The ways to fill in the `?`s of `line` to match `groups`, given `num_in_group` damaged
+/// springs already counted towards the first group. Call it with an empty `cache`.
+pub fn num_arrangements<'a>(
+    line: &'a str,
+    num_in_group: usize,
+    groups: &'a [usize],
+    cache: &mut HashMap<(&'a str, usize, &'a [usize]), usize>,
+) -> usize {
+    if line.is_empty() {
+        return if num_in_group == 0 && groups.is_empty() { 1 } else { 0 };
+    }
+
+    let cache_key = (line, num_in_group, groups);
+    if let Some(&count) = cache.get(&cache_key) {
+        return count;
+    }
+
+    let mut count = 0;
+    let c = &line[0..1];
+
+    if c == "." || c == "?" {
+        if num_in_group > 0 {
+            if num_in_group == groups[0] {
+                count += num_arrangements(&line[1..], 0, &groups[1..], cache);
+            }
+        } else {
+            count += num_arrangements(&line[1..], num_in_group, groups, cache);
+        }
+    }
+    if c == "#" || c == "?" {
+        if !groups.is_empty() && num_in_group < groups[0] {
+            count += num_arrangements(&line[1..], num_in_group + 1, groups, cache);
+        }
+    }
+    cache.insert(cache_key, count);
+    count
+}
+
+/// The sum of the arrangements of every row.
+pub fn part1(input: &[(String, Vec<usize>)]) -> usize {
+    input
+        .iter()
+        .map(|(line, groups)| {
+            let mut cache = HashMap::new();
+            num_arrangements(
+                (line.clone() + ".").as_str(),
+                0,
+                &groups[0..groups.len()],
+                &mut cache,
+            )
+        })
+        .sum()
+}
+
+/// The sum of the arrangements of every row unfolded five times.
+pub fn part2(input: &[(String, Vec<usize>)]) -> usize {
+    input
+        .iter()
+        .map(|(line, groups)| {
+            let mut cache = HashMap::new();
+            let line = iter::repeat(line).take(5).join("?") + ".";
+            let groups = groups.repeat(5);
+            num_arrangements(line.as_str(), 0, &groups[0..groups.len()], &mut cache)
+        })
+        .sum()
+}
+
+/// `size` rows of up to 20 springs, at least one of them damaged, with some springs hidden.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        let mut springs = (0..rng.gen_range(1..=20))
+            .map(|_| rng.gen_bool(0.5))
+            .collect_vec();
+        let at = rng.gen_range(0..springs.len());
+        springs[at] = true;
+        let groups = springs
+            .iter()
+            .group_by(|&&damaged| damaged)
+            .into_iter()
+            .filter(|(damaged, _)| *damaged)
+            .map(|(_, run)| run.count())
+            .join(",");
+        let line: String = springs
+            .iter()
+            .map(|&damaged| match (damaged, rng.gen_bool(0.4)) {
+                (_, true) => '?',
+                (true, false) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        res.push_str(&format!("{line} {groups}\n"));
+    }
+    res
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Vec<(String, Vec<usize>)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use super::{parse_input, part1, part2, Day12};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day12);
+
+    /// The lengths of the runs of damaged springs.
+    fn groups(springs: &[char]) -> Vec<usize> {
+        springs
+            .iter()
+            .group_by(|&&c| c)
+            .into_iter()
+            .filter(|(c, _)| *c == '#')
+            .map(|(_, run)| run.count())
+            .collect()
+    }
+
+    /// Try every way of filling in the unknown springs.
+    fn brute_force(line: &str, expected: &[usize]) -> usize {
+        let unknown = line.chars().filter(|&c| c == '?').count();
+        (0..1 << unknown)
+            .filter(|&damaged: &u32| {
+                let mut i = 0;
+                let springs = line
+                    .chars()
+                    .map(|c| match c {
+                        '?' => {
+                            i += 1;
+                            if damaged & (1 << (i - 1)) != 0 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        }
+                        c => c,
+                    })
+                    .collect_vec();
+                groups(&springs) == expected
+            })
+            .count()
+    }
+
+    /// A row of springs with some hidden, and the groups of the row before they were hidden, so
+    /// there's always at least one arrangement.
+    fn row() -> impl Strategy<Value = (String, Vec<usize>)> {
+        prop::collection::vec((any::<bool>(), any::<bool>()), 0..14).prop_map(|springs| {
+            let known = springs
+                .iter()
+                .map(|&(damaged, _)| if damaged { '#' } else { '.' });
+            let groups = groups(&known.collect_vec());
+            let line = springs
+                .iter()
+                .map(|&(damaged, hidden)| match (damaged, hidden) {
+                    (_, true) => '?',
+                    (true, false) => '#',
+                    (false, false) => '.',
+                })
+                .collect();
+            (line, groups)
+        })
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 12).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 12, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 12).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 12, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_part1(
+            line in "[.#?]{0,14}",
+            groups in prop::collection::vec(1..5usize, 0..4),
+        ) {
+            let expected = brute_force(&line, &groups);
+            prop_assert_eq!(part1(&[(line, groups)]), expected);
+        }
+
+        #[test]
+        fn prop_part1_solvable((line, groups) in row()) {
+            let expected = brute_force(&line, &groups);
+            prop_assert!(expected > 0);
+            prop_assert_eq!(part1(&[(line, groups)]), expected);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Day 13: Point of Incidence. Lines of reflection in patterns of ash and rocks.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// The patterns, split on blank lines.
+pub fn parse_input(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    let mut res = Vec::new();
+    let mut curr = Vec::new();
+    for line in parse::lines(Day13::DAY, input) {
+        if line.is_empty() {
+            res.push(parse::grid_from_lines(curr.drain(..), ".#")?);
+        } else {
+            curr.push(line);
+        }
+    }
+    if !curr.is_empty() {
+        res.push(parse::grid_from_lines(curr.into_iter(), ".#")?);
+    }
+    Ok(res.into_iter().map(Grid::from_rows).collect())
+}
+
+fn result_for_diffs(grids: &[Grid<char>], count: usize) -> usize {
+    grids
+        .iter()
+        .map(|grid| {
+            // A horizontal line of reflection is a vertical one in the transposed grid.
+            if let Some(col) = reflection(grid, count) {
+                col
+            } else if let Some(row) = reflection(&grid.transpose(), count) {
+                100 * row
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// The number of columns left of the first vertical line of reflection with `count` smudges.
+pub fn reflection(grid: &Grid<char>, count: usize) -> Option<usize> {
+    (0..grid.width().saturating_sub(1))
+        .find(|&col| vertical_diffs(grid, col) == count)
+        .map(|col| col + 1)
+}
+
+fn vertical_diffs(grid: &Grid<char>, col: usize) -> usize {
+    let n_cols = grid.width();
+    (0..(col + 1).min(n_cols - col - 1))
+        .flat_map(|d| {
+            grid.rows()
+                .filter(move |row| row[col - d] != row[col + d + 1])
+        })
+        .count()
+}
+
+/// The summary of the lines of reflection.
+pub fn part1(grids: &[Grid<char>]) -> usize {
+    result_for_diffs(grids, 0)
+}
+
+/// The summary of the lines of reflection once each smudge is cleaned.
+pub fn part2(grids: &[Grid<char>]) -> usize {
+    result_for_diffs(grids, 1)
+}
+
+/// `size` patterns, each with exactly one line of reflection and exactly one line that reflects
+/// with a single smudge.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut patterns = Vec::new();
+    while patterns.len() < size {
+        let (height, width) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+        let mut rows = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect_vec()
+            })
+            .collect_vec();
+        // Mirror the rows about one line, which mustn't be in the middle so that some rows are
+        // left over, then the columns about another.
+        let row = rng.gen_range(1..height);
+        if 2 * row == height {
+            continue;
+        }
+        for r in row..height.min(2 * row) {
+            rows[r] = rows[2 * row - 1 - r].clone();
+        }
+        let col = rng.gen_range(1..width);
+        for cells in rows.iter_mut() {
+            for c in col..width.min(2 * col) {
+                cells[c] = cells[2 * col - 1 - c];
+            }
+        }
+        // Smudge a leftover row within the columns' reflection.
+        let reach = row.min(height - row);
+        let r = (0..height)
+            .filter(|&r| r + reach < row || r >= row + reach)
+            .collect_vec();
+        let r = *r.choose(rng).unwrap();
+        let c = rng.gen_range(col - col.min(width - col)..col + col.min(width - col));
+        rows[r][c] = if rows[r][c] == '#' { '.' } else { '#' };
+
+        let mut grid = Grid::from_rows(rows);
+        if rng.gen_bool(0.5) {
+            grid = grid.transpose();
+        }
+        // Chance may have made other lines that reflect.
+        let transposed = grid.transpose();
+        let diffs = (0..grid.width() - 1)
+            .map(|c| vertical_diffs(&grid, c))
+            .chain((0..grid.height() - 1).map(|r| vertical_diffs(&transposed, r)))
+            .collect_vec();
+        if diffs.iter().filter(|&&d| d == 0).count() == 1
+            && diffs.iter().filter(|&&d| d == 1).count() == 1
+        {
+            patterns.push(grid.to_string());
+        }
+    }
+    patterns.join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Grid<char>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day13};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day13);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 13).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 13, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 13).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 13, 2));
+    }
+}
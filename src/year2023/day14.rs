@@ -0,0 +1,194 @@
+//! Day 14: Parabolic Reflector Dish. Rolling round rocks around a platform.
+
+use anyhow::{bail, Result};
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::debug;
+
+use crate::cycle::{Cycle, History};
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Parameters, Solution};
+use crate::viz::{self, Frame};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(Day14::DAY, input, ".#O")
+}
+
+/// Roll every round rock as far as it will go in the given direction.
+pub fn tilt(grid: &mut Grid<char>, dir: Dir) {
+    // Move the rocks nearest the far edge first, so the rest come to rest against them.
+    let positions = grid.positions();
+    let positions: Box<dyn Iterator<Item = Pos>> = match dir {
+        Dir::North | Dir::West => Box::new(positions),
+        Dir::South | Dir::East => Box::new(positions.rev()),
+    };
+    for source in positions {
+        if grid[source] != 'O' {
+            continue;
+        }
+
+        let mut target = source;
+        while grid.get(target.step(dir)) == Some(&'.') {
+            target = target.step(dir);
+        }
+        if target != source {
+            grid[target] = 'O';
+            grid[source] = '.';
+        }
+    }
+}
+
+/// The total load on the north support beams.
+pub fn count_load(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|&(_, &c)| c == 'O')
+        .map(|(pos, _)| grid.height() - pos.row as usize)
+        .sum()
+}
+
+/// The load after tilting north.
+pub fn part1(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
+    tilt(&mut grid, Dir::North);
+    debug!("tilted north:\n{grid}");
+    count_load(&grid)
+}
+
+/// One spin cycle: tilt north, then west, south and east.
+pub fn spin(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    for dir in [Dir::North, Dir::West, Dir::South, Dir::East] {
+        tilt(&mut grid, dir);
+    }
+    grid
+}
+
+/// The load after `cycles` spin cycles.
+pub fn part2(grid: &Grid<char>, cycles: usize) -> usize {
+    let history = History::find(grid.clone(), spin);
+    let Cycle { start, period } = history.cycle;
+    debug!(start, period, "spin cycles repeat");
+    count_load(history.nth(cycles))
+}
+
+fn frame(grid: &Grid<char>) -> Frame {
+    Frame::new(grid, |&c| match c {
+        'O' => viz::ORANGE,
+        '#' => viz::GREY,
+        _ => viz::BLACK,
+    })
+}
+
+/// The platform after each tilt of the first few spin cycles.
+fn visualise(grid: &Grid<char>, cycles: usize) -> Vec<Frame> {
+    let mut grid = grid.clone();
+    let mut res = vec![frame(&grid)];
+    for _ in 0..cycles {
+        for dir in [Dir::North, Dir::West, Dir::South, Dir::East] {
+            tilt(&mut grid, dir);
+            res.push(frame(&grid));
+        }
+    }
+    res
+}
+
+/// How many spin cycles to run in part 2.
+pub struct Params {
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { cycles: 1_000_000_000 }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "cycles" => self.cycles = value.parse()?,
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// A `size` by `size` platform of round and cube-shaped rocks.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        res.extend((0..size).map(|_| match rng.gen_range(0..100) {
+            0..=19 => 'O',
+            20..=34 => '#',
+            _ => '.',
+        }));
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Grid<char>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part2(input, params.cycles).into())
+    }
+
+    fn visualise(input: &Self::Input, _params: &Self::Params) -> Result<Vec<Frame>> {
+        Ok(visualise(input, 3))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, spin, Day14};
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day14);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 14).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 14, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_input(&read_input(2023, 14).unwrap()).unwrap(),
+            1_000_000_000,
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 14, 2));
+    }
+
+    #[test]
+    fn test_spin_non_square() {
+        let grid = parse_input(&fixtures::input(2023, 14, "wide").unwrap()).unwrap();
+        assert_eq!(
+            spin(&grid).to_string(),
+            ".O#...O#.#.\n...O#.....O\n#.....O#..O\n......OO#.O\n.OOO#...OO#\n"
+        );
+    }
+}
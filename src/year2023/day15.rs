@@ -0,0 +1,142 @@
+//! Day 15: Lens Library. The HASH algorithm and the lenses it files into boxes.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Check every step is either `label-` or `label=N`, but keep the raw text since part 1 hashes
+/// the steps as written.
+///
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let input = input.trim_end();
+    for line in parse::lines(Day15::DAY, input) {
+        for step in line.split(",") {
+            if step.strip_suffix("-").is_err() {
+                let (_, focal_len) = step.split_once("=")?;
+                focal_len.parse::<usize>()?;
+            }
+        }
+    }
+    Ok(input.to_string())
+}
+
+/// The HASH of a string: a value from 0 to 255.
+pub fn hash(s: &str) -> u64 {
+    s.bytes().fold(0u64, |current_value, b| {
+        ((current_value + b as u64) * 17) % 256
+    })
+}
+
+/// The sum of the HASH of every step.
+pub fn part1(input: &str) -> u64 {
+    input.split(",").map(|v| hash(v)).sum()
+}
+
+/// The focusing power of the lenses once every step has run.
+pub fn part2(input: &str) -> usize {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+
+    for cmd in input.split(",") {
+        match cmd.split_once(&['-', '='][..]) {
+            Some((label, "")) => boxes[hash(label) as usize].retain(|(l, _)| l[..] != label[..]),
+            Some((label, len_str)) => {
+                let focal_len = len_str.parse::<usize>().unwrap();
+                let the_box = &mut boxes[hash(label) as usize];
+                if let Some((index, _)) = the_box
+                    .iter()
+                    .find_position(|(other_label, _)| other_label[0..] == label[0..])
+                {
+                    the_box[index] = (label, focal_len);
+                } else {
+                    the_box.push((label, focal_len));
+                }
+            }
+            _ => unreachable!("parse_input only accepts `-` and `=` steps"),
+        }
+    }
+
+    let mut res = 0;
+    for (box_num, the_box) in boxes.iter().enumerate() {
+        for (slot_num, &(_, focal_len)) in the_box.iter().enumerate() {
+            res += (box_num + 1) * (slot_num + 1) * focal_len;
+        }
+    }
+    res
+}
+
+/// `size` steps, drawing on a few labels so that lenses get replaced and removed.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let labels = (0..size / 4 + 1)
+        .map(|_| {
+            let len = rng.gen_range(2..=6);
+            (0..len)
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect::<String>()
+        })
+        .collect_vec();
+    let steps = (0..size.max(1))
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .join(",");
+    steps + "\n"
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = String;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day15};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day15);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 15).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 15, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 15).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 15, 2));
+    }
+}
@@ -0,0 +1,185 @@
+//! Day 16: The Floor Will Be Lava. Beams of light bouncing around a contraption.
+
+use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::viz::{self, Frame};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(Day16::DAY, input, ".|-/\\")
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+struct State {
+    pos: Pos,
+    dir: Dir,
+}
+
+/// Every position and direction the beam passes through.
+fn trace_beam(grid: &Grid<char>, initial: State) -> HashSet<State> {
+    let mut seen = HashSet::new();
+
+    let mut q = VecDeque::new();
+    q.push_back(initial);
+
+    while let Some(state @ State { pos, dir }) = q.pop_front() {
+        if !seen.insert(state) {
+            continue;
+        }
+
+        let new_dirs = match (grid[pos], dir) {
+            ('\\', Dir::South) | ('/', Dir::North) => vec![Dir::East],
+            ('\\', Dir::North) | ('/', Dir::South) => vec![Dir::West],
+            ('\\', Dir::East) | ('/', Dir::West) => vec![Dir::South],
+            ('\\', Dir::West) | ('/', Dir::East) => vec![Dir::North],
+            ('|', _) if !dir.is_vertical() => vec![Dir::North, Dir::South],
+            ('-', _) if dir.is_vertical() => vec![Dir::West, Dir::East],
+            _ => vec![dir],
+        };
+
+        for dir in new_dirs {
+            let pos = pos.step(dir);
+            if grid.contains(pos) {
+                q.push_back(State { pos, dir });
+            }
+        }
+    }
+    seen
+}
+
+fn count_energized(grid: &Grid<char>, initial: State) -> usize {
+    let energized: HashSet<Pos> = trace_beam(grid, initial).iter().map(|s| s.pos).collect();
+    energized.len()
+}
+
+/// The tiles energized by a beam entering the top-left heading right.
+pub fn part1(grid: &Grid<char>) -> usize {
+    let initial = State { pos: Pos::new(0, 0), dir: Dir::East };
+    count_energized(grid, initial)
+}
+
+/// The most tiles energized by a beam entering from any edge.
+pub fn part2(grid: &Grid<char>) -> usize {
+    let last_row = grid.height() as i64 - 1;
+    let last_col = grid.width() as i64 - 1;
+    let from_sides = (0..=last_row).flat_map(|row| {
+        [
+            State { pos: Pos::new(row, 0), dir: Dir::East },
+            State { pos: Pos::new(row, last_col), dir: Dir::West },
+        ]
+    });
+    let from_ends = (0..=last_col).flat_map(|col| {
+        [
+            State { pos: Pos::new(0, col), dir: Dir::South },
+            State { pos: Pos::new(last_row, col), dir: Dir::North },
+        ]
+    });
+    from_sides
+        .chain(from_ends)
+        .map(|initial| count_energized(grid, initial))
+        .max()
+        .unwrap()
+}
+
+/// The contraption in grey, with a heat map of how many directions the part 1 beam crosses each
+/// tile in.
+fn visualise(grid: &Grid<char>) -> Frame {
+    let initial = State { pos: Pos::new(0, 0), dir: Dir::East };
+    let mut crossings = HashMap::new();
+    for state in trace_beam(grid, initial) {
+        *crossings.entry(state.pos).or_insert(0.) += 1.;
+    }
+    let mut frame = Frame::new(grid, |&c| if c == '.' { viz::BLACK } else { viz::GREY });
+    frame.heat_map(crossings);
+    frame
+}
+
+/// A `size` by `size` contraption with a mirror or splitter in about one cell in ten.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut res = String::new();
+    for _ in 0..size {
+        res.extend((0..size).map(|_| {
+            if rng.gen_bool(0.1) {
+                *b"/\\|-".choose(rng).unwrap() as char
+            } else {
+                '.'
+            }
+        }));
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn visualise(input: &Self::Input, _params: &()) -> Result<Vec<Frame>> {
+        Ok(vec![visualise(input)])
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day16};
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day16);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 16).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 16, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 16).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 16, 2));
+    }
+
+    #[test]
+    fn test_part2_non_square() {
+        // Reflected in the diagonal, the tall contraption is a wide one with the same best beam.
+        // The mirrors keep their shape and the splitters swap.
+        let tall = parse_input(&fixtures::input(2023, 16, "tall").unwrap()).unwrap();
+        let wide = tall.transpose().map(|&c| match c {
+            '|' => '-',
+            '-' => '|',
+            c => c,
+        });
+        assert_eq!((tall.height(), wide.height()), (8, 4));
+        assert_eq!(part2(&tall), 11);
+        assert_eq!(part2(&wide), 11);
+    }
+}
@@ -0,0 +1,156 @@
+//! Day 17: Clumsy Crucible. The path of least heat loss for crucibles that can't turn freely.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::viz::{self, Frame};
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let grid = Grid::parse(Day17::DAY, input, "0123456789")?;
+    Ok(grid.map(|c| c.to_digit(10).unwrap()))
+}
+
+/// A crucible's position, the straight steps it has taken to get there, and its heading.
+pub type State = (Pos, i32, Dir);
+
+/// The least heat loss from the top-left to the bottom-right, and the path that loses it.
+pub fn min_heat_loss(
+    grid: &Grid<u32>,
+    min_steps_to_turn: i32,
+    max_forward_steps: i32,
+) -> Option<(u32, Vec<Pos>)> {
+    let target = Pos::new(grid.height() as i64 - 1, grid.width() as i64 - 1);
+    let mut distances = HashMap::new();
+    let mut came_from: HashMap<State, State> = HashMap::new();
+    let mut q = BinaryHeap::new();
+    let start = (Pos::new(0, 0), 1, Dir::East);
+    q.push(Reverse((0, start, start)));
+
+    while let Some(Reverse((distance, state @ (pos, steps, dir), prior))) = q.pop() {
+        if let Some(&old_distance) = distances.get(&state) {
+            if old_distance <= distance {
+                continue;
+            }
+        }
+        distances.insert(state, distance);
+        came_from.insert(state, prior);
+
+        // The crucible can only stop at the end once it is allowed to turn.
+        if pos == target && steps >= min_steps_to_turn {
+            let mut path = vec![pos];
+            let mut state = state;
+            while state != start {
+                state = came_from[&state];
+                path.push(state.0);
+            }
+            path.reverse();
+            return Some((distance, path));
+        }
+
+        for new_dir in [Dir::South, Dir::East, Dir::North, Dir::West] {
+            let new_pos = pos.step(new_dir);
+            if grid.contains(new_pos)
+                && (new_dir != dir || steps < max_forward_steps)
+                && (new_dir == dir || steps >= min_steps_to_turn)
+                && new_dir != dir.reverse()
+            {
+                let new_steps = if dir == new_dir { steps + 1 } else { 1 };
+                let new_dist = distance + grid[new_pos];
+                q.push(Reverse((new_dist, (new_pos, new_steps, new_dir), state)));
+            }
+        }
+    }
+    None
+}
+
+/// The least heat loss for a crucible that goes at most three blocks straight.
+pub fn part1(grid: &Grid<u32>) -> u32 {
+    min_heat_loss(grid, 0, 3).map_or(0, |(loss, _)| loss)
+}
+
+/// The least heat loss for an ultra crucible, which goes four to ten blocks straight.
+pub fn part2(grid: &Grid<u32>) -> u32 {
+    min_heat_loss(grid, 4, 10).map_or(0, |(loss, _)| loss)
+}
+
+/// A heat map of the city blocks' heat loss, with the ultra crucible's path over it in white.
+fn visualise(grid: &Grid<u32>) -> Frame {
+    let mut frame = Frame::new(grid, |_| viz::BLACK);
+    frame.heat_map(grid.iter().map(|(pos, &loss)| (pos, loss as f64)));
+    if let Some((_, path)) = min_heat_loss(grid, 4, 10) {
+        frame.highlight(path, viz::WHITE);
+    }
+    frame
+}
+
+/// A `size` by `size` city of heat losses from 1 to 9. Cities smaller than 5 by 5 are made 5 by
+/// 5, so the ultra crucible can reach the end.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    let mut res = String::new();
+    for _ in 0..size {
+        res.extend((0..size).map(|_| rng.gen_range('1'..='9')));
+        res.push('\n');
+    }
+    res
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Grid<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn visualise(input: &Self::Input, _params: &()) -> Result<Vec<Frame>> {
+        Ok(vec![visualise(input)])
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day17};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day17);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 17).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 17, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 17).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 17, 2));
+    }
+}
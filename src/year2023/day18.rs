@@ -0,0 +1,400 @@
+//! Day 18: Lavaduct Lagoon. The area of the lagoon dug out by the dig plan.
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::{debug, trace};
+
+use crate::parse::{self, ParseError, Token};
+use crate::solution::{Answer, Solution};
+
+/// The outline must turn a corner at every step, otherwise `area` can't tell which side of the
+/// edge is inside.
+///
+fn check_turns(
+    input: &str,
+    steps: Vec<(Token, (char, i64))>,
+) -> Result<Vec<(char, i64)>, ParseError> {
+    if steps.is_empty() {
+        return Err(parse::end_of_input(Day18::DAY, input, "a dig plan"));
+    }
+    let horizontal = |dir: char| dir == 'L' || dir == 'R';
+    for (i, (_, (dir, _))) in steps.iter().enumerate() {
+        let (token, (next_dir, _)) = &steps[(i + 1) % steps.len()];
+        if horizontal(*dir) == horizontal(*next_dir) {
+            return Err(token.error(format!("expected a turn after `{dir}`")));
+        }
+    }
+    Ok(steps.into_iter().map(|(_, step)| step).collect_vec())
+}
+
+/// Each step of the dig plan as a direction, `U`, `D`, `L` or `R`, and a distance.
+pub fn parse_input(input: &str) -> Result<Vec<(char, i64)>, ParseError> {
+    let steps = parse::lines(Day18::DAY, input)
+        .map(|line| {
+            let [dir, num, _color] = line.split_n(line.split_whitespace())?;
+            let dir_char = match dir.text() {
+                "L" | "R" | "U" | "D" => dir.text().chars().next().unwrap(),
+                _ => return Err(dir.error("expected `L`, `R`, `U` or `D`")),
+            };
+            Ok((dir, (dir_char, num.parse::<i64>()?)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    check_turns(input, steps)
+}
+
+/// Whether the outline runs clockwise, with down the page as positive y, going by the sign of
+/// the shoelace sum over the corners of the trench's centre line.
+fn is_clockwise(plan: &[(char, i64)]) -> bool {
+    let (mut x, mut y, mut sum) = (0, 0, 0);
+    for &(dir, num) in plan {
+        let (next_x, next_y) = match dir {
+            'L' => (x - num, y),
+            'R' => (x + num, y),
+            'U' => (x, y - num),
+            _ => (x, y + num),
+        };
+        sum += x * next_y - next_x * y;
+        (x, y) = (next_x, next_y);
+    }
+    sum > 0
+}
+
+/// The area of the lagoon, counting the trench as well as what it encloses.
+pub fn area(plan: &[(char, i64)]) -> i64 {
+    // Which side of each edge is inside is worked out below for a clockwise outline, so walk an
+    // anticlockwise one backwards.
+    if !is_clockwise(plan) {
+        let reversed = plan
+            .iter()
+            .rev()
+            .map(|&(dir, num)| {
+                let opposite = match dir {
+                    'L' => 'R',
+                    'R' => 'L',
+                    'U' => 'D',
+                    _ => 'U',
+                };
+                (opposite, num)
+            })
+            .collect_vec();
+        return area(&reversed);
+    }
+
+    let mut vertices = Vec::new();
+    let mut x = 0;
+    let mut y = 0;
+    vertices.push((0, 0));
+    let mut prior_inside = false;
+
+    // In order to use the shoelace formula, we need to convert the row/column edge to x,y
+    // vertices where the edges are inside the line segments describe by the vertices.
+    for (i, (dir, num)) in plan.iter().enumerate() {
+        let next_dir = if i < plan.len() - 1 { plan[i + 1].0 } else { plan[0].0 };
+        let next_inside = match (dir, next_dir) {
+            ('L', 'U') => false,
+            ('L', 'D') => true,
+            ('D', 'L') => false,
+            ('D', 'R') => true,
+            ('R', 'U') => true,
+            ('R', 'D') => false,
+            ('U', 'L') => true,
+            ('U', 'R') => false,
+            _ => unreachable!("parse_input checks every step turns a corner"),
+        };
+
+        match (dir, next_inside, prior_inside) {
+            ('L', false, false) => x += -*num - 1,
+            ('L', true, true) => x += -*num + 1,
+            ('L', _, _) => x += -*num,
+            ('D', false, false) => y += *num + 1,
+            ('D', true, true) => y += *num - 1,
+            ('D', _, _) => y += *num,
+            ('R', false, false) => x += *num + 1,
+            ('R', true, true) => x += *num - 1,
+            ('R', _, _) => x += *num,
+            ('U', false, false) => y += -*num - 1,
+            ('U', true, true) => y += -*num + 1,
+            ('U', _, _) => y += -*num,
+            _ => unreachable!("parse_input only accepts L, D, R and U"),
+        };
+        prior_inside = next_inside;
+        vertices.push((x, y));
+    }
+
+    // Compute area using the shoelace formula.
+    let mut area = 0;
+    for ((x1, y1), (x2, y2)) in vertices.iter().tuple_windows() {
+        let len = x1 * y2 - x2 * y1;
+        trace!(x1, y1, x2, y2, len, "shoelace term");
+        area += len
+    }
+    debug!(
+        vertices = vertices.len(),
+        area = area / 2,
+        "dug out the lagoon"
+    );
+    area / 2
+}
+
+/// The area dug out by the plan.
+pub fn part1(plan: &[(char, i64)]) -> i64 {
+    area(plan)
+}
+
+/// The dig plan hidden in the colours.
+pub fn parse_input2(input: &str) -> Result<Vec<(char, i64)>, ParseError> {
+    let steps = parse::lines(Day18::DAY, input)
+        .map(|line| {
+            let [_dir, _num, color] = line.split_n(line.split_whitespace())?;
+            let color = color.strip_prefix("(#")?.strip_suffix(")")?;
+            let (num, dir) = (color.slice(0..5)?, color.slice(5..6)?);
+            let num = i64::from_str_radix(num.text(), 16)
+                .map_err(|_| num.error("expected a 5 digit hex distance"))?;
+            let dir_char = match dir.text() {
+                "0" => 'R',
+                "1" => 'D',
+                "2" => 'L',
+                "3" => 'U',
+                _ => return Err(dir.error("expected a direction digit from 0 to 3")),
+            };
+            Ok((dir, (dir_char, num)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    check_turns(input, steps)
+}
+
+/// The area dug out by the plan in the colours.
+pub fn part2(plan: &[(char, i64)]) -> i64 {
+    area(plan)
+}
+
+/// A clockwise outline around columns of the given widths, reaching `tops` above a centre line
+/// and `bottoms` below it. Neighbouring columns must differ in both so every step turns.
+fn skyline(widths: &[i64], tops: &[i64], bottoms: &[i64]) -> Vec<(char, i64)> {
+    let vertical = |from: i64, to: i64| if to > from { ('U', to - from) } else { ('D', from - to) };
+    let n = widths.len();
+    let mut plan = Vec::new();
+    for i in 0..n {
+        plan.push(('R', widths[i]));
+        if i + 1 < n {
+            plan.push(vertical(tops[i], tops[i + 1]));
+        }
+    }
+    plan.push(('D', tops[n - 1] + bottoms[n - 1]));
+    for i in (0..n).rev() {
+        plan.push(('L', widths[i]));
+        if i > 0 {
+            plan.push(vertical(-bottoms[i], -bottoms[i - 1]));
+        }
+    }
+    plan.push(('U', tops[0] + bottoms[0]));
+    plan
+}
+
+/// Two outlines of `size` columns each, one in the directions and one in the colours. The
+/// colours' distances are up to half a million.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let n = size.max(1);
+    let mut outline = |max: i64| {
+        let mut heights = || {
+            let mut res: Vec<i64> = vec![rng.gen_range(1..=max)];
+            while res.len() < n {
+                let height = rng.gen_range(1..=max);
+                if height != res[res.len() - 1] {
+                    res.push(height);
+                }
+            }
+            res
+        };
+        let (tops, bottoms) = (heights(), heights());
+        let widths = (0..n).map(|_| rng.gen_range(1..=max)).collect_vec();
+        skyline(&widths, &tops, &bottoms)
+    };
+    let (plan, colours) = (outline(10), outline(500_000));
+
+    let mut res = String::new();
+    for ((dir, num), (colour_dir, colour_num)) in plan.into_iter().zip(colours) {
+        let digit = "RDLU".find(colour_dir).unwrap();
+        res.push_str(&format!("{dir} {num} (#{colour_num:05x}{digit})\n"));
+    }
+    res
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = (Vec<(char, i64)>, Vec<(char, i64)>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_input(input)?, parse_input2(input)?))
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(&input.0).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(&input.1).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::{area, parse_input, parse_input2, part1, part2, Day18};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day18);
+
+    /// The cells the trench passes through, in order, or `None` if it crosses or retraces itself.
+    fn trench(plan: &[(char, i64)]) -> Option<Vec<(i64, i64)>> {
+        let mut cells = vec![(0, 0)];
+        for &(dir, num) in plan {
+            let (dx, dy) = match dir {
+                'L' => (-1, 0),
+                'R' => (1, 0),
+                'U' => (0, -1),
+                _ => (0, 1),
+            };
+            for _ in 0..num {
+                let (x, y) = *cells.last().unwrap();
+                cells.push((x + dx, y + dy));
+            }
+        }
+        let end = cells.pop()?;
+        let unique = cells.iter().collect::<HashSet<_>>().len() == cells.len();
+        (end == (0, 0) && unique).then_some(cells)
+    }
+
+    /// Dig the trench cell by cell and flood fill from outside it to find what's left inside.
+    fn brute_force(plan: &[(char, i64)]) -> i64 {
+        let trench = trench(plan).unwrap().into_iter().collect::<HashSet<_>>();
+        let min_x = trench.iter().map(|&(x, _)| x).min().unwrap() - 1;
+        let max_x = trench.iter().map(|&(x, _)| x).max().unwrap() + 1;
+        let min_y = trench.iter().map(|&(_, y)| y).min().unwrap() - 1;
+        let max_y = trench.iter().map(|&(_, y)| y).max().unwrap() + 1;
+        let mut outside = HashSet::from([(min_x, min_y)]);
+        let mut q = vec![(min_x, min_y)];
+        while let Some((x, y)) = q.pop() {
+            for next @ (x, y) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                let on_grid = (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+                if on_grid && !trench.contains(&next) && outside.insert(next) {
+                    q.push(next);
+                }
+            }
+        }
+        (max_x - min_x + 1) * (max_y - min_y + 1) - outside.len() as i64
+    }
+
+    /// A closed plan of alternating horizontal and vertical steps, in either direction around,
+    /// that may touch itself but never crosses or retraces itself.
+    fn plan() -> impl Strategy<Value = Vec<(char, i64)>> {
+        (1..4usize)
+            .prop_flat_map(|n| {
+                let steps = prop::collection::vec(prop_oneof![-6..=-1i64, 1..=6i64], n);
+                (steps.clone(), steps, 0..2 * n + 2)
+            })
+            .prop_filter_map("the last steps must be non-zero", |(xs, ys, rotate)| {
+                // The last step along each axis brings the outline back to the start.
+                let close = |mut steps: Vec<i64>| {
+                    let last = -steps.iter().sum::<i64>();
+                    steps.push(last);
+                    (last != 0).then_some(steps)
+                };
+                let mut plan = Vec::new();
+                for (x, y) in close(xs)?.into_iter().zip(close(ys)?) {
+                    plan.push(if x < 0 { ('L', -x) } else { ('R', x) });
+                    plan.push(if y < 0 { ('U', -y) } else { ('D', y) });
+                }
+                plan.rotate_left(rotate);
+                Some(plan)
+            })
+            .prop_filter("the outline must not cross itself", |plan| {
+                trench(plan).is_some()
+            })
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 18).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 18, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input2(&read_input(2023, 18).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 18, 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("R 6 (#70c710)\nL 5 (#0dc571)\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "L"));
+        let err = parse_input2("R 6 (#70c714)\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 12, "4"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_area(plan in plan()) {
+            prop_assert_eq!(area(&plan), brute_force(&plan));
+        }
+    }
+}
+
+/* Notes
+Working through the example getting the vertices right.
+
+ 0 1 2 3 4 5 6 7
+  # # # # # # #
+ 1
+  # . . . . . #
+ 2
+  # # # . . . #
+ 3
+  . . # . . . #
+ 4
+  . . # . . . #
+ 5
+  # # # . # # #
+ 6
+  # . . . # . .
+ 7
+  # # . . # # #
+ 8
+  . # . . . . #
+ 9
+  . # # # # # #
+10
+
+(0, 0), (7, 0) =>  0
+(7, 0), (7, 6) => 42
+(7, 6), (5, 6) => 42 - 30 = 12
+(5, 6), (5, 7) => 35 - 30 = 5
+(5, 7), (7, 7) => 35 - 49 = -14
+(7, 7), (7, 10) => 70 - 49 = 21
+(7, 10), (1, 10) => 70 - 10 = 60
+(1, 10), (1, 8) => 8 - 10 = -2
+(1, 8), (0, 8) => 8
+(0, 8), (0, 5) => 0
+(0, 5), (2, 5) => -10
+(2, 5), (2, 3) => 6 - 10 = -4
+(2, 3), (0, 3) => 6
+(0, 3), (0, 0) => 0
+*/
@@ -0,0 +1,434 @@
+//! Day 19: Aplenty. Workflows that accept or reject machine parts by their ratings.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::gen;
+use crate::graphviz::{Dot, ToDot};
+use crate::parse::{self, ParseError, Token};
+use crate::solution::{Answer, Solution};
+
+/// Indices of the ratings in a part.
+pub const X: usize = 0;
+pub const M: usize = 1;
+pub const A: usize = 2;
+pub const S: usize = 3;
+
+/// Where a part goes next.
+pub enum Destination {
+    Workflow(String),
+    Accept,
+    Reject,
+}
+
+/// How a rule compares a rating.
+pub enum Op {
+    Lt,
+    Gt,
+}
+
+/// A named list of rules, tried in order.
+pub struct Workflow {
+    /// The rating, `X`, `M`, `A` or `S`, compared with the value, and where the part goes if
+    /// the comparison holds.
+    pub rules: Vec<(usize, Op, u64, Destination)>,
+    /// Where the part goes if no rule matches.
+    pub otherwise: Destination,
+}
+
+/// The workflows by name, and the `x`, `m`, `a` and `s` ratings of each part.
+pub type System = (HashMap<String, Workflow>, Vec<[u64; 4]>);
+
+fn parse_destination<'a>(dest: Token<'a>, targets: &mut Vec<Token<'a>>) -> Destination {
+    match dest.text() {
+        "A" => Destination::Accept,
+        "R" => Destination::Reject,
+        x => {
+            targets.push(dest);
+            Destination::Workflow(x.to_string())
+        }
+    }
+}
+
+/// The first destination, in input order, that closes a loop of workflows, with the workflow
+/// it is in. `links` pairs each workflow with each of its destinations that is a workflow.
+fn find_loop<'a>(links: &[(&'a str, Token<'a>)]) -> Option<(&'a str, Token<'a>)> {
+    fn visit<'a>(
+        name: &'a str,
+        next: &HashMap<&str, Vec<Token<'a>>>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<(&'a str, Token<'a>)> {
+        path.push(name);
+        for &to in next.get(name).into_iter().flatten() {
+            if path.contains(&to.text()) {
+                return Some((name, to));
+            }
+            if !done.contains(to.text()) {
+                if let Some(found) = visit(to.text(), next, path, done) {
+                    return Some(found);
+                }
+            }
+        }
+        path.pop();
+        done.insert(name);
+        None
+    }
+
+    let mut next: HashMap<&str, Vec<Token>> = HashMap::new();
+    for &(from, to) in links {
+        next.entry(from).or_default().push(to);
+    }
+    // Workflows from which every path ends in an accept or reject.
+    let mut done = HashSet::new();
+    links.iter().find_map(|&(from, _)| {
+        if done.contains(from) {
+            None
+        } else {
+            visit(from, &next, &mut Vec::new(), &mut done)
+        }
+    })
+}
+
+fn parse_prop(prop: Token) -> Result<usize, ParseError> {
+    match prop.text() {
+        "m" => Ok(M),
+        "s" => Ok(S),
+        "a" => Ok(A),
+        "x" => Ok(X),
+        _ => Err(prop.error("expected `x`, `m`, `a` or `s`")),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<System, ParseError> {
+    let mut workflows = HashMap::new();
+    let mut parts = Vec::new();
+    let mut targets = Vec::new();
+    let mut links = Vec::new();
+
+    let mut parse_workflows = true;
+    for line in parse::lines(Day19::DAY, input) {
+        if line.is_empty() {
+            parse_workflows = false;
+            continue;
+        }
+        if parse_workflows {
+            let mut rules = Vec::new();
+            let mut otherwise = Destination::Accept;
+            let (name, rest) = line.split_once("{")?;
+            let first_target = targets.len();
+            for rule in rest.strip_suffix("}")?.split(",") {
+                if let Ok((condition, dest)) = rule.split_once(":") {
+                    let prop = parse_prop(condition.slice(0..1)?)?;
+                    let op_token = condition.slice(1..2)?;
+                    let op = match op_token.text() {
+                        ">" => Op::Gt,
+                        "<" => Op::Lt,
+                        _ => return Err(op_token.error("expected `<` or `>`")),
+                    };
+                    let value = condition.slice(2..condition.text().len())?.parse::<u64>()?;
+                    let dest = parse_destination(dest, &mut targets);
+                    rules.push((prop, op, value, dest))
+                } else {
+                    otherwise = parse_destination(rule, &mut targets);
+                }
+            }
+            links.extend(targets[first_target..].iter().map(|&to| (name.text(), to)));
+            workflows.insert(name.text().to_string(), Workflow { rules, otherwise });
+        } else {
+            let mut part = [0u64; 4];
+            for prop in line.strip_prefix("{")?.strip_suffix("}")?.split(",") {
+                let (name, value) = prop.split_once("=")?;
+                part[parse_prop(name)?] = value.parse::<u64>()?;
+            }
+            parts.push(part);
+        }
+    }
+    if let Some(target) = targets.iter().find(|t| !workflows.contains_key(t.text())) {
+        return Err(target.error("expected the name of a workflow"));
+    }
+    if let Some((from, to)) = find_loop(&links) {
+        return Err(to.error(format!(
+            "expected a workflow that doesn't lead back to `{from}`"
+        )));
+    }
+    if !workflows.contains_key("in") {
+        return Err(parse::end_of_input(
+            Day19::DAY,
+            input,
+            "a workflow named `in`",
+        ));
+    }
+    Ok((workflows, parts))
+}
+
+/// Whether the part ends up accepted, starting from `in`.
+pub fn is_accepted(workflows: &HashMap<String, Workflow>, part: &[u64; 4]) -> bool {
+    let mut workflow_name = &"in".to_string();
+    loop {
+        let mut workflow_dest = None;
+        let workflow = workflows.get(workflow_name).unwrap();
+        for (prop, op, value, dest) in &workflow.rules {
+            if match op {
+                Op::Gt => part[*prop] > *value,
+                Op::Lt => part[*prop] < *value,
+            } {
+                workflow_dest = Some(dest);
+                break;
+            }
+        }
+        if workflow_dest.is_none() {
+            workflow_dest = Some(&workflow.otherwise);
+        }
+        match workflow_dest.unwrap() {
+            Destination::Accept => return true,
+            Destination::Reject => return false,
+            Destination::Workflow(name) => workflow_name = name,
+        }
+    }
+}
+
+/// The sum of the ratings of the accepted parts.
+pub fn part1((workflows, parts): &System) -> u64 {
+    parts
+        .iter()
+        .filter(|p| is_accepted(workflows, p))
+        .map(|p| p.iter().sum::<u64>())
+        .sum()
+}
+
+/// The combinations of ratings within `ranges`, inclusive, accepted from `dest`.
+pub fn count_combinations(
+    dest: &Destination,
+    workflows: &HashMap<String, Workflow>,
+    ranges: [[u64; 2]; 4],
+) -> u64 {
+    let mut combinations = 0;
+    let workflow_name;
+
+    match dest {
+        Destination::Accept => {
+            return ranges
+                .iter()
+                .map(|[b, e]| (e + 1).saturating_sub(*b))
+                .product::<u64>()
+        }
+        Destination::Reject => return 0,
+        Destination::Workflow(name) => workflow_name = name,
+    }
+
+    let mut new_ranges = ranges.clone();
+    let workflow = workflows.get(workflow_name).unwrap();
+
+    for (prop, op, value, dest) in &workflow.rules {
+        let mut next_ranges = new_ranges.clone();
+        match op {
+            // A rule can only narrow the ranges, and may leave one empty, e.g. for `x<0`.
+            Op::Gt => {
+                next_ranges[*prop][0] = next_ranges[*prop][0].max(value.saturating_add(1));
+                new_ranges[*prop][1] = new_ranges[*prop][1].min(*value);
+            }
+            Op::Lt => {
+                next_ranges[*prop][1] = next_ranges[*prop][1].min(value.saturating_sub(1));
+                new_ranges[*prop][0] = new_ranges[*prop][0].max(*value);
+            }
+        }
+        combinations += count_combinations(dest, workflows, next_ranges)
+    }
+    combinations + count_combinations(&workflow.otherwise, workflows, new_ranges)
+}
+
+/// The combinations of ratings from 1 to 4000 that are accepted.
+pub fn part2((workflows, _): &System) -> u64 {
+    count_combinations(
+        &Destination::Workflow("in".to_string()),
+        workflows,
+        [[1, 4000]; 4],
+    )
+}
+
+/// The tree of workflows from `in`, each rule an edge labelled with its condition. Every accept
+/// and reject is a leaf of its own, so the drawing stays a tree.
+impl ToDot for System {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::digraph();
+        dot.attr("rankdir", "LR");
+        for (name, workflow) in self.0.iter().sorted_by_key(|(name, _)| name.as_str()) {
+            dot.node(name, &[("shape", "box")]);
+            let rules = workflow
+                .rules
+                .iter()
+                .map(|(prop, op, value, dest)| {
+                    let op = match op {
+                        Op::Lt => '<',
+                        Op::Gt => '>',
+                    };
+                    (format!("{}{op}{value}", ['x', 'm', 'a', 's'][*prop]), dest)
+                })
+                .chain([("else".to_string(), &workflow.otherwise)]);
+            for (i, (condition, dest)) in rules.enumerate() {
+                let leaf = format!("{name}:{i}");
+                let to = match dest {
+                    Destination::Workflow(to) => to,
+                    Destination::Accept => {
+                        dot.node(&leaf, &[("label", "A"), ("color", "green")]);
+                        &leaf
+                    }
+                    Destination::Reject => {
+                        dot.node(&leaf, &[("label", "R"), ("color", "red")]);
+                        &leaf
+                    }
+                };
+                dot.edge(name, to, &[("label", &condition)]);
+            }
+        }
+        dot
+    }
+}
+
+/// Up to `size` workflows in a tree from `in`, so none loops back, and `size` parts. Each
+/// condition splits the ratings that reach it into two ranges that both have ratings in them.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut names = gen::names(rng, size.saturating_sub(1), 3, &["in"]);
+    let mut todo = vec![("in".to_string(), [[1u64, 4000]; 4])];
+    let mut lines = Vec::new();
+    while let Some((name, mut ranges)) = todo.pop() {
+        let mut destination = |rng: &mut StdRng, ranges: [[u64; 2]; 4]| {
+            if !names.is_empty() && rng.gen_bool(0.7) {
+                let name = names.pop().unwrap();
+                todo.push((name.clone(), ranges));
+                name
+            } else if rng.gen_bool(0.5) {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            }
+        };
+        let mut rules = Vec::new();
+        for _ in 0..rng.gen_range(1..=3) {
+            let Some(&prop) = [X, M, A, S]
+                .iter()
+                .copied()
+                .filter(|&prop| ranges[prop][0] < ranges[prop][1])
+                .collect_vec()
+                .choose(rng)
+            else {
+                break;
+            };
+            let [lo, hi] = ranges[prop];
+            let mut matched = ranges;
+            let (op, value) = if rng.gen_bool(0.5) {
+                let value = rng.gen_range(lo..hi);
+                (matched[prop], ranges[prop]) = ([value + 1, hi], [lo, value]);
+                ('>', value)
+            } else {
+                let value = rng.gen_range(lo + 1..=hi);
+                (matched[prop], ranges[prop]) = ([lo, value - 1], [value, hi]);
+                ('<', value)
+            };
+            let dest = destination(rng, matched);
+            rules.push(format!("{}{op}{value}:{dest}", ['x', 'm', 'a', 's'][prop]));
+        }
+        rules.push(destination(rng, ranges));
+        lines.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+    lines.shuffle(rng);
+
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .join("\n");
+    format!("{}\n\n{parts}\n", lines.join("\n"))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = System;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(input.to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day19};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day19);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 19).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 19, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 19).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 19, 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("in{a<2006:qkq,q>2090:A,R}\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 15, "q"));
+        let err = parse_input("in{a<2006:qkq,R}\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 11, "qkq"));
+    }
+
+    #[test]
+    fn test_parse_loop() {
+        let err = parse_input("in{a<2006:qkq,A}\nqkq{x>5:R,in}\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 11, "in"));
+        assert_eq!(
+            err.message,
+            "expected a workflow that doesn't lead back to `qkq`"
+        );
+        let err = parse_input("in{A}\nab{s<3:ab,R}\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 8, "ab"));
+        // Two routes to the same workflow are not a loop.
+        assert!(parse_input("in{a<5:ab,cd}\ncd{ab}\nab{R}\n").is_ok());
+    }
+
+    #[test]
+    fn test_part2_empty_ranges() {
+        let system = parse_input("in{x<0:A,x>4000:A,x<10:R,x<5:A,R}\n").unwrap();
+        assert_eq!(part2(&system), 0);
+        let system = parse_input("in{x>10:R,x>20:A,A}\n").unwrap();
+        assert_eq!(part2(&system), 10 * 4000u64.pow(3));
+    }
+}
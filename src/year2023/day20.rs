@@ -0,0 +1,347 @@
+//! Day 20: Pulse Propagation. Pulses sent through a network of flip-flops and conjunctions.
+
+use crate::cycle::{self, History};
+use crate::gen;
+use crate::graphviz::{Dot, ToDot};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Pulse {
+    Low = 0,
+    High = 1,
+}
+
+/// A flip-flop and whether it is on, or a conjunction and the last pulse from each input,
+/// `true` for high.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Module {
+    FlipFlip { on: bool },
+    Conjunction { inputs: BTreeMap<String, bool> },
+}
+
+/// The state of every module, ordered so it can be hashed.
+pub type Modules = BTreeMap<String, Module>;
+
+/// The modules each module sends to, and the state of every module.
+pub type Network = (HashMap<String, Vec<String>>, Modules);
+
+pub fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let mut connections = HashMap::new();
+    let mut modules = BTreeMap::new();
+    for line in parse::lines(Day20::DAY, input) {
+        let (module, dest) = line.split_once(" -> ")?;
+        let dest = dest.split(", ").map(|s| s.text().to_string()).collect_vec();
+        if module.text() == "broadcaster" {
+            connections.insert(module.text().to_string(), dest);
+            continue;
+        }
+
+        let name = module
+            .strip_prefix("%")
+            .or_else(|_| module.strip_prefix("&"));
+        let Ok(name) = name else {
+            return Err(
+                module.error("expected `broadcaster` or a module name prefixed by `%` or `&`")
+            );
+        };
+        let module_name = name.text().to_string();
+        let module = match module.text().chars().next() {
+            Some('%') => Module::FlipFlip { on: false },
+            _ => Module::Conjunction { inputs: BTreeMap::new() },
+        };
+
+        connections.insert(module_name.clone(), dest);
+        modules.insert(module_name, module);
+    }
+    for (source, destinations) in connections.iter() {
+        for dest in destinations {
+            if let Some(Module::Conjunction { inputs, .. }) = modules.get_mut(dest) {
+                inputs.insert(source.clone(), false);
+            }
+        }
+    }
+
+    if !connections.contains_key("broadcaster") {
+        return Err(parse::end_of_input(
+            Day20::DAY,
+            input,
+            "a `broadcaster` module",
+        ));
+    }
+    Ok((connections, modules))
+}
+
+/// Push the button once, updating `modules`, and count the low and high pulses each module
+/// receives.
+pub fn push_button<'a>(
+    connections: &'a HashMap<String, Vec<String>>,
+    modules: &'a mut Modules,
+) -> (HashMap<&'a String, u64>, HashMap<&'a String, u64>) {
+    let mut low_count = HashMap::new();
+    let mut high_count = HashMap::new();
+
+    let broadcaster = connections.keys().find(|&s| s == "broadcaster").unwrap();
+    let mut q = VecDeque::new();
+    for dest in connections.get(broadcaster).unwrap() {
+        q.push_back((dest, Pulse::Low, broadcaster));
+    }
+    low_count.insert(broadcaster, 1);
+
+    while let Some((name, pulse, from)) = q.pop_front() {
+        if pulse == Pulse::High {
+            high_count.insert(name, high_count.get(name).unwrap_or(&0) + 1);
+        } else {
+            low_count.insert(name, low_count.get(name).unwrap_or(&0) + 1);
+        }
+        let new_pulse = match modules.get_mut(name) {
+            Some(Module::FlipFlip { on }) => {
+                if let Pulse::Low = pulse {
+                    if *on {
+                        *on = false;
+                        Some(Pulse::Low)
+                    } else {
+                        *on = true;
+                        Some(Pulse::High)
+                    }
+                } else {
+                    None
+                }
+            }
+            Some(Module::Conjunction { inputs }) => {
+                inputs.insert(from.clone(), pulse == Pulse::High);
+                if inputs.values().all(|&b| b) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+            None => None,
+        };
+
+        if let Some(new_pulse) = new_pulse {
+            for dest in connections.get(name).unwrap() {
+                q.push_back((dest, new_pulse, name));
+            }
+        }
+    }
+    (low_count, high_count)
+}
+
+/// The product of the low and high pulses sent in a thousand pushes.
+pub fn part1((connections, modules): &Network) -> u64 {
+    let mut modules = modules.clone();
+    let mut low_count = 0;
+    let mut high_count = 0;
+    for _ in 0..1000 {
+        let (l, h) = push_button(connections, &mut modules);
+        low_count += l.values().sum::<u64>();
+        high_count += h.values().sum::<u64>();
+    }
+    low_count * high_count
+}
+
+/// The modules upstream of `name`, itself included, whose states depend on nothing else.
+fn upstream(connections: &HashMap<String, Vec<String>>, modules: &Modules, name: &str) -> Modules {
+    let mut seen = HashSet::from([name]);
+    let mut q = vec![name];
+    while let Some(dest) = q.pop() {
+        for (source, dests) in connections {
+            if dests.iter().any(|d| d == dest) && seen.insert(source) {
+                q.push(source);
+            }
+        }
+    }
+    modules
+        .iter()
+        .filter(|(name, _)| seen.contains(name.as_str()))
+        .map(|(name, module)| (name.clone(), module.clone()))
+        .collect()
+}
+
+/// `None` if `rx` is never sent a low pulse, or not within `u64::MAX` presses.
+pub fn part2((connections, modules): &Network) -> Option<u64> {
+    // rx is fed by a single conjunction, so gets a low pulse when every input to that conjunction
+    // sends it a high pulse in the same press. Each input is driven by its own sub-network, whose
+    // state cycles independently of the others.
+    let (feeder, _) = connections
+        .iter()
+        .find(|(_, dests)| dests.iter().any(|d| d == "rx"))?;
+    let Some(Module::Conjunction { inputs }) = modules.get(feeder) else {
+        return None;
+    };
+    let sources = inputs
+        .keys()
+        .map(|input| {
+            // Only the sub-network is simulated, so any high pulse to the feeder is from `input`.
+            let press = |modules: &Modules| {
+                let mut modules = modules.clone();
+                let (_, high) = push_button(connections, &mut modules);
+                (high.contains_key(feeder), modules)
+            };
+            let history = History::find(upstream(connections, modules, input), |m| press(m).1);
+            // State `i` is the one before press `i + 1`.
+            let hits = history.positions(|m| press(m).0);
+            (history.cycle, hits)
+        })
+        .collect_vec();
+    cycle::first_common(&sources, 0).map(|presses| presses + 1)
+}
+
+/// The wiring between modules, with the broadcaster drawn as a double circle, flip-flops as boxes
+/// and conjunctions as trapezia, labelled with their prefixes as in the input.
+impl ToDot for Network {
+    fn to_dot(&self) -> Dot {
+        let (connections, modules) = self;
+        let mut dot = Dot::digraph();
+        for (name, dests) in connections.iter().sorted() {
+            match modules.get(name) {
+                Some(Module::FlipFlip { .. }) => {
+                    dot.node(name, &[("shape", "box"), ("label", &format!("%{name}"))]);
+                }
+                Some(Module::Conjunction { .. }) => {
+                    dot.node(
+                        name,
+                        &[("shape", "invtrapezium"), ("label", &format!("&{name}"))],
+                    );
+                }
+                None => {
+                    dot.node(name, &[("shape", "doublecircle")]);
+                }
+            }
+            for dest in dests {
+                dot.edge(name, dest, &[]);
+            }
+        }
+        dot
+    }
+}
+
+/// Four counters of `size` flip-flops, from 2 to 12, wired like the puzzle's. Each counts button
+/// presses in binary until its conjunction sees its own odd number of presses, then resets
+/// itself and sends a high pulse on through an inverter to the conjunction that feeds `rx`.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let bits = size.clamp(2, 12);
+    let mut names = gen::names(rng, 4 * (bits + 2) + 1, 2, &["rx"]);
+    let feeder = names.pop().unwrap();
+    let mut lines = vec![format!("&{feeder} -> rx")];
+    let mut firsts = Vec::new();
+    for _ in 0..4 {
+        let flip_flops = names.split_off(names.len() - bits);
+        let [counter, inverter] = [(); 2].map(|_| names.pop().unwrap());
+        let count = (1 << (bits - 1)) | rng.gen_range(0..1 << (bits - 1)) | 1;
+
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut dests = flip_flops.get(bit + 1).into_iter().cloned().collect_vec();
+            if count & (1 << bit) != 0 {
+                dests.push(counter.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            dests.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", dests.join(", ")));
+        }
+        resets.shuffle(rng);
+        resets.push(inverter.clone());
+        lines.push(format!("&{counter} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {feeder}"));
+        firsts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = Network;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        part2(input)
+            .map(Answer::from)
+            .ok_or_else(|| anyhow!("rx is never sent a low pulse"))
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(input.to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day20};
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::graphviz::ToDot;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day20);
+
+    #[test]
+    fn test_dot() {
+        let dot = parse_input(&fixtures::input(2023, 20, "example2").unwrap())
+            .unwrap()
+            .to_dot()
+            .to_string();
+        assert_eq!(
+            dot,
+            "digraph G {
+    a [shape=box, label=\"%a\"]
+    a -> inv
+    a -> con
+    b [shape=box, label=\"%b\"]
+    b -> con
+    broadcaster [shape=doublecircle]
+    broadcaster -> a
+    con [shape=invtrapezium, label=\"&con\"]
+    con -> output
+    inv [shape=invtrapezium, label=\"&inv\"]
+    inv -> b
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 20).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 20, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 20).unwrap()).unwrap()).unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 20, 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("broadcaster -> a\n#a -> b\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "#a"));
+    }
+}
@@ -0,0 +1,284 @@
+//! Day 21: Step Counter. Garden plots reachable in an exact number of steps.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{bail, Result};
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::{debug, trace, Level};
+
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Parameters, Solution};
+use crate::viz::{self, Frame};
+
+/// The garden and where the elf starts.
+pub type Garden = (Grid<char>, Pos);
+
+pub fn parse_input(input: &str) -> Result<Garden, ParseError> {
+    let grid = Grid::parse(Day21::DAY, input, ".#S")?;
+    match grid.find(|&c| c == 'S') {
+        Some(start) => Ok((grid, start)),
+        None => Err(parse::end_of_input(
+            Day21::DAY,
+            input,
+            "a starting position `S`",
+        )),
+    }
+}
+
+/// The fewest steps to each plot within `max_steps` of the start.
+pub fn fewest_steps((grid, start): &Garden, max_steps: usize) -> HashMap<Pos, usize> {
+    let mut seen = HashMap::new();
+    let mut q = VecDeque::new();
+    q.push_back((*start, 0));
+
+    while let Some((pos, steps)) = q.pop_front() {
+        if steps > max_steps {
+            continue;
+        }
+        if let Entry::Vacant(entry) = seen.entry(pos) {
+            entry.insert(steps);
+        } else {
+            continue;
+        }
+
+        for dir in [Dir::North, Dir::West, Dir::South, Dir::East] {
+            // The garden repeats infinitely in every direction.
+            let new_pos = pos.step(dir);
+            if *grid.get_wrapping(new_pos) != '#' {
+                q.push_back((new_pos, steps + 1));
+            }
+        }
+    }
+    seen
+}
+
+/// Plots the elf can end on after exactly `max_steps`: any reached in fewer steps of the same
+/// parity, since the elf can step back and forth.
+pub fn count_plots(garden: &Garden, max_steps: usize) -> i64 {
+    let seen = fewest_steps(garden, max_steps);
+
+    if tracing::enabled!(Level::TRACE) {
+        let mut reached = garden.0.clone();
+        for (&pos, &steps) in &seen {
+            if let Some(c) = reached.get_mut(pos).filter(|_| steps % 2 == max_steps % 2) {
+                *c = 'O';
+            }
+        }
+        trace!(
+            "plots reached in {max_steps} steps, within the first copy of the garden:\n{reached}"
+        );
+    }
+
+    seen.values()
+        .filter(|&steps| steps % 2 == max_steps % 2)
+        .count() as i64
+}
+
+/// The plots the elf could be on after each step, up to `max_steps`, within the first copy of
+/// the garden.
+fn visualise(garden @ (grid, start): &Garden, max_steps: usize) -> Vec<Frame> {
+    let seen = fewest_steps(garden, max_steps);
+    let base = Frame::new(grid, |&c| if c == '#' { viz::GREY } else { viz::BLACK });
+    (0..=max_steps)
+        .map(|step| {
+            let mut frame = base.clone();
+            let reached = seen
+                .iter()
+                .filter(|&(_, &steps)| steps <= step && steps % 2 == step % 2)
+                .map(|(&pos, _)| pos);
+            frame
+                .highlight(reached, viz::GREEN)
+                .highlight([*start], viz::RED);
+            frame
+        })
+        .collect()
+}
+
+/// The plots the elf can end on after `steps`.
+pub fn part1(garden: &Garden, steps: usize) -> i64 {
+    count_plots(garden, steps)
+}
+
+/// The plots the elf can end on after `steps` in the infinitely tiled garden. The fit only holds
+/// for a square garden, and `steps` of half its side more than a multiple of it.
+pub fn part2(garden: &Garden, steps: usize) -> Result<u64> {
+    // Had to get a hint on this one. I was on the way to curve fitting, but couldn't
+    // find a clean way to do it. One of the nicest solutions uses this lagrange interpolation
+    // equation to find the coefficients of the second order polynomial that you can see
+    // when you plot the counts as a function of steps.
+
+    // This equation takes three points on the curve. We choose x=[65, 65 + 131, 65 + 131*2],
+    // because the number of steps has the property 65 = 26501365 % 131. Where 131 is the
+    // dimension of the input and 65 is half that (minus the starting point).
+    //
+    // So 65 is the intercept and 131 and 131*2 are the points on the curve.
+
+    // The formula is:
+    // f(x) = [(x – x1)(x – x2)/(x0 – x1)(x0 – x2)]×y0
+    //      + [(x – x0)(x – x2)/(x1 – x0)(x1 – x2)]×y1
+    //      + [(x – x0)(x – x1)/(x2 – x0)(x2 – x1)]×y2
+
+    // If x0 = 0, x1, = 1 and x=2 is 2, then:
+    // (x – x1)(x – x2)/(x0 – x1)(x0 – x2) = (x - 1)(x - 2)/2 = (x^2 - 3x + 2)/2
+    // (x – x0)(x – x2)/(x1 – x0)(x1 – x2) = x*(x - 2)/1*(1-2) = -(x^2 - 2x)
+    // (x – x0)(x – x1)/(x2 – x0)(x2 – x1) = x*(x-1)/2 = (x^2 - x)/2
+
+    // f(x) = (x^2 - 3x + 2)*y0/2 - (x^2 - 2x)*y1 + (x^2 - x)*y2/2
+    //      = x^2*y0/2 - 3x*y0/2+ 2*y0/2 - x^2*y1 + 2x*y1 + x^2*y2/2 - x*y2/2
+    //      = (x^2*y0/2 - x^2*y1 + x^2*y2/2) + (-3x*y0/2 + 2x*y1 - x*y2/2) + (2*y0/2)
+    //      = (y0/2 - y1 + y2/2)*x^2 + (-3*y0/2 + 2*y1 - y2/2)*x + y0
+    //
+    // So for the formula y = a*x^2 + b*x + c
+    // a = y0/2 - y1 + y2/2
+    // b = -3*y0/2 + 2*y1 - y2/2
+    // c = y0
+    let (width, dim) = (garden.0.width(), garden.0.height());
+    if width != dim {
+        bail!("the garden is {width} wide and {dim} tall, but it must be square");
+    }
+    let half = dim / 2;
+    if steps < half || !(steps - half).is_multiple_of(dim) {
+        bail!("{steps} steps is not {half} more than a multiple of the garden's side, {dim}");
+    }
+
+    let y0 = count_plots(garden, half) as f64;
+    let y1 = count_plots(garden, half + dim) as f64;
+    let y2 = count_plots(garden, half + 2 * dim) as f64;
+
+    let a = y0 / 2. - y1 + y2 / 2.;
+    let b = -3. * y0 / 2. + 2. * y1 - y2 / 2.;
+    let c = y0;
+    debug!(y0, y1, y2, a, b, c, "fitted the plot counts");
+
+    // We fit the curve with x=[0, 1, 2], but the x values we actually have are
+    // 65, 65 + 131 and 65 + 131*2.
+    //
+    // We'll adjust our target x by performing the same transformation to arrive at an x in
+    // the same units as our interpolation: multiples of 131 offset by 65.
+    let target = (steps - half) as f64 / dim as f64;
+    Ok((a * target * target + b * target + c) as u64)
+}
+
+/// Steps the elf takes: part 1 walks the plot itself, part 2 walks the infinitely tiled garden.
+pub struct Params {
+    pub steps: usize,
+    pub tiled_steps: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { steps: 64, tiled_steps: 26_501_365 }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "steps" => self.steps = value.parse()?,
+            "tiled_steps" => self.tiled_steps = value.parse()?,
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// A garden `2 * size + 1` plots square with the start in the middle, and its middle row and
+/// column and its edges clear of rocks, which part 2's fitting relies on. Part 2's default step
+/// count only lines up with a garden 131 plots square, i.e. size 65; for other sizes pass
+/// `tiled_steps` of `size` more than a multiple of the width.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let dim = 2 * size + 1;
+    let mut grid = Grid::new(dim, dim, '.');
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let clear = [pos.row, pos.col]
+            .iter()
+            .any(|&x| x == 0 || x == size as i64 || x == dim as i64 - 1);
+        if !clear && rng.gen_bool(0.1) {
+            grid[pos] = '#';
+        }
+    }
+    grid[Pos::new(size as i64, size as i64)] = 'S';
+    grid.to_string()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Garden;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part1(input, params.steps).into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part2(input, params.tiled_steps)?.into())
+    }
+
+    fn visualise(input: &Self::Input, params: &Self::Params) -> Result<Vec<Frame>> {
+        Ok(visualise(input, params.steps))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day21};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day21);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 21).unwrap()).unwrap(), 64);
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 21, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_input(&read_input(2023, 21).unwrap()).unwrap(),
+            26_501_365,
+        )
+        .unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 21, 2));
+    }
+
+    #[test]
+    fn test_part2_unaligned_steps() {
+        let garden = parse_input(&read_input(2023, 21).unwrap()).unwrap();
+        assert!(part2(&garden, 26_501_366).is_err());
+        assert!(part2(&garden, 64).is_err());
+    }
+
+    #[test]
+    fn test_part2_non_square() {
+        let garden = parse_input("...\n.S.\n...\n...\n").unwrap();
+        assert_eq!(
+            part2(&garden, 7).unwrap_err().to_string(),
+            "the garden is 3 wide and 4 tall, but it must be square"
+        );
+        let garden = parse_input("...\n.S.\n...\n").unwrap();
+        assert_eq!(
+            part2(&garden, 6).unwrap_err().to_string(),
+            "6 steps is not 1 more than a multiple of the garden's side, 3"
+        );
+        assert!(part2(&garden, 7).is_ok());
+    }
+}
@@ -0,0 +1,230 @@
+//! Day 22: Sand Slabs. Bricks that settle onto each other and what falls if one goes.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::graphviz::{Dot, ToDot};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Each brick as `[x1, x2, y1, y2, z1, z2]`.
+pub fn parse_input(input: &str) -> Result<Vec<[usize; 6]>, ParseError> {
+    parse::lines(Day22::DAY, input)
+        .map(|line| {
+            let [x1, y1, z1, x2, y2, z2] = line.split_n(line.split_any(&[',', '~']))?;
+            let [x1, x2, y1, y2, z1, z2] = [x1, x2, y1, y2, z1, z2].map(|s| s.parse::<usize>());
+            Ok([x1?, x2?, y1?, y2?, z1?, z2?])
+        })
+        .collect()
+}
+
+/// Drop the bricks, sorted from the lowest up, into place and list the bricks each one rests
+/// on.
+pub fn support_graph(pieces: &mut [[usize; 6]]) -> Vec<Vec<usize>> {
+    pieces.sort_by_key(|v| v[4]);
+
+    let mut max_z = 1;
+    let mut dropped: Vec<(usize, [usize; 6])> = Vec::new();
+    let mut supporting = Vec::new();
+    for _ in 0..pieces.len() {
+        supporting.push(Vec::new())
+    }
+
+    for (i, &[x1, x2, y1, y2, z1, z2]) in pieces.iter().enumerate() {
+        let mut new_z = 0;
+        for z in (1..=max_z).rev() {
+            let intersecting_pieces = dropped
+                .iter()
+                .filter_map(|&(j, [ox1, ox2, oy1, oy2, oz1, oz2])| {
+                    if x1.max(ox1) <= x2.min(ox2)
+                        && y1.max(oy1) <= y2.min(oy2)
+                        && z >= oz1
+                        && z <= oz2
+                    {
+                        Some(j)
+                    } else {
+                        None
+                    }
+                })
+                .collect_vec();
+
+            if !intersecting_pieces.is_empty() {
+                new_z = z + 1;
+                supporting[i].extend(intersecting_pieces);
+                break;
+            }
+            new_z = z;
+        }
+        let z_len = z2 - z1;
+        dropped.push((i, [x1, x2, y1, y2, new_z, new_z + z_len]));
+        max_z = max_z.max(new_z + z_len);
+    }
+
+    supporting
+}
+
+/// The number of other bricks that fall if `target` is taken away.
+pub fn falling_count(target: usize, supporting: &[Vec<usize>]) -> usize {
+    let mut falling = HashSet::new();
+    falling.insert(target);
+
+    for _ in target + 1..supporting.len() {
+        let new_falling: HashSet<usize> = supporting
+            .iter()
+            .enumerate()
+            .filter_map(|(i, d)| {
+                if !d.is_empty() && d.iter().all(|j| falling.contains(j)) {
+                    Some(i)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if new_falling.len() == falling.len() - 1 {
+            break;
+        }
+        falling.extend(new_falling);
+    }
+    falling.len() - 1
+}
+
+/// The number of bricks that can be taken away without any other falling.
+pub fn part1(pieces: &[[usize; 6]]) -> usize {
+    let mut pieces = pieces.to_vec();
+    let supporting = support_graph(&mut pieces);
+
+    (0..pieces.len())
+        .filter(|&i| !supporting.iter().any(|v| v.len() == 1 && v[0] == i))
+        .count()
+}
+
+/// The sum of the bricks that fall when each brick is taken away in turn.
+pub fn part2(pieces: &[[usize; 6]]) -> usize {
+    let mut pieces = pieces.to_vec();
+    let supporting = support_graph(&mut pieces);
+
+    (0..pieces.len())
+        .map(|i| falling_count(i, &supporting))
+        .sum()
+}
+
+/// The bricks once they have settled, and for each the bricks it rests on.
+struct Supports {
+    bricks: Vec<[usize; 6]>,
+    supporting: Vec<Vec<usize>>,
+}
+
+impl Supports {
+    fn new(bricks: &[[usize; 6]]) -> Self {
+        let mut bricks = bricks.to_vec();
+        let supporting = support_graph(&mut bricks);
+        Supports { bricks, supporting }
+    }
+}
+
+/// Each brick above the bricks it rests on, labelled with its snapshot position. Bricks that are
+/// the only support of another, and so can't be disintegrated, are shaded.
+impl ToDot for Supports {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::digraph();
+        dot.attr("rankdir", "BT");
+        for (i, [x1, x2, y1, y2, z1, z2]) in self.bricks.iter().enumerate() {
+            let label = format!("{x1},{y1},{z1}~{x2},{y2},{z2}");
+            if self.supporting.iter().any(|v| v[..] == [i]) {
+                dot.node(&i.to_string(), &[("label", &label), ("style", "filled")]);
+            } else {
+                dot.node(&i.to_string(), &[("label", &label)]);
+            }
+        }
+        for (i, below) in self.supporting.iter().enumerate() {
+            for j in below {
+                dot.edge(&j.to_string(), &i.to_string(), &[]);
+            }
+        }
+        dot
+    }
+}
+
+/// `size` bricks up to four cubes long, each dropped from above all the ones before onto an area
+/// that widens with `size`, up to 10 by 10 as in the puzzle, so that they pile up and rest on
+/// each other.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = ((size as f64).sqrt().ceil() as usize).clamp(3, 10);
+    let mut top = 0;
+    let mut bricks = Vec::new();
+    for _ in 0..size {
+        let mut extent = [0; 3];
+        extent[rng.gen_range(0..3)] = rng.gen_range(0..width.min(4));
+        let x = rng.gen_range(0..width - extent[0]);
+        let y = rng.gen_range(0..width - extent[1]);
+        let z = top + rng.gen_range(1..=3);
+        top = z + extent[2];
+        bricks.push(format!(
+            "{x},{y},{z}~{},{},{}",
+            x + extent[0],
+            y + extent[1],
+            z + extent[2]
+        ));
+    }
+    bricks.shuffle(rng);
+    bricks.join("\n") + "\n"
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<[usize; 6]>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(Supports::new(input).to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day22};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day22);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 22).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 22, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 22).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 22, 2));
+    }
+}
@@ -0,0 +1,350 @@
+//! Day 23: A Long Walk. The longest hike through the forest without stepping twice on a tile.
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use tracing::{debug, trace};
+
+use crate::graphviz::{Dot, ToDot};
+use crate::grid::{Dir, Grid, Pos};
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::viz::{self, Frame};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(Day23::DAY, input, "#.><^v")
+}
+
+const START: Pos = Pos::new(0, 1);
+
+/// The gap in the bottom wall, one column in from the right.
+fn end(grid: &Grid<char>) -> Pos {
+    Pos::new(grid.height() as i64 - 1, grid.width() as i64 - 2)
+}
+
+/// The longest hike, walking over slopes in any direction if `ignore_slopes`.
+pub fn longest_walk(grid: &Grid<char>, ignore_slopes: bool) -> u64 {
+    let target = end(grid);
+    let mut seen = HashMap::new();
+    let mut q = BinaryHeap::new();
+    let mut paths = HashMap::new();
+    let mut counter = 0;
+
+    q.push((0, START, counter));
+    paths.insert(counter, HashSet::new());
+
+    while let Some((steps, p, id)) = q.pop() {
+        if let Some(&max_steps) = seen.get(&p) {
+            if max_steps >= steps {
+                continue;
+            }
+        }
+        seen.insert(p, steps);
+
+        let ch = grid[p];
+        let mut path = paths.remove(&id).unwrap();
+        path.insert(p);
+
+        for (dir, slope) in [
+            (Dir::South, 'v'),
+            (Dir::East, '>'),
+            (Dir::North, '^'),
+            (Dir::West, '<'),
+        ] {
+            if !ignore_slopes && ch != '.' && ch != slope {
+                continue;
+            }
+
+            let new_p = p.step(dir);
+            if grid.get(new_p).is_some_and(|&c| c != '#') && !path.contains(&new_p) {
+                counter += 1;
+                paths.insert(counter, path.clone());
+                q.push((steps + 1, new_p, counter));
+            }
+        }
+    }
+
+    *seen.get(&target).unwrap()
+}
+
+/// The longest hike going down slopes only the way they point.
+pub fn part1(grid: &Grid<char>) -> u64 {
+    longest_walk(grid, false)
+}
+
+/// The paths out of each junction, to the next junction and how many steps away it is.
+pub type Graph = HashMap<Pos, Vec<(Pos, usize)>>;
+
+/// The junctions, named `row,col`, with an edge for each path between two of them labelled with
+/// its length. The start and the end, which no path leaves, are shaded.
+impl ToDot for Graph {
+    fn to_dot(&self) -> Dot {
+        let id = |p: &Pos| format!("{},{}", p.row, p.col);
+        let mut dot = Dot::graph();
+        dot.node(&id(&START), &[("style", "filled")]);
+        let ends = self.values().flatten().map(|(to, _)| to);
+        for end in ends.filter(|&to| !self.contains_key(to)).unique() {
+            dot.node(&id(end), &[("style", "filled")]);
+        }
+        // Paths are found from both ends, so keep each once.
+        let paths = self
+            .iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |(to, steps)| (from, to, steps)))
+            .map(|(from, to, steps)| (from.min(to), from.max(to), steps))
+            .sorted()
+            .dedup();
+        for (from, to, steps) in paths {
+            dot.edge(&id(from), &id(to), &[("label", &steps.to_string())]);
+        }
+        dot
+    }
+}
+
+/// Follow the corridor from `junction` through `first` until it reaches another junction or a
+/// dead end. Returns the cells walked, ending at the far end, and the ways on from there.
+fn corridor(grid: &Grid<char>, junction: Pos, first: Pos) -> (Vec<Pos>, Vec<Pos>) {
+    let mut cells = vec![first];
+    let mut prior_p = junction;
+    loop {
+        let p = *cells.last().unwrap();
+        let next_steps: Vec<Pos> = grid
+            .neighbours4(p)
+            .filter(|&new_p| grid[new_p] != '#' && new_p != prior_p)
+            .collect();
+        if next_steps.len() != 1 {
+            return (cells, next_steps);
+        }
+        prior_p = p;
+        cells.push(next_steps[0]);
+    }
+}
+
+pub fn to_graph(grid: &Grid<char>) -> Graph {
+    // Convert the difficult-to-work-with grid into a graph. Returns a map of edges
+    // (source -> destination) with associated number of steps
+    let mut seen = HashSet::new();
+    let mut q = VecDeque::new();
+    let mut res: Graph = HashMap::new();
+    q.push_back((START, START.step(Dir::South)));
+
+    while let Some(key @ (intersection, start)) = q.pop_front() {
+        if !seen.insert(key) {
+            continue;
+        }
+
+        let (cells, next_steps) = corridor(grid, intersection, start);
+        let p = *cells.last().unwrap();
+        res.entry(intersection).or_default().push((p, cells.len()));
+        for next in next_steps {
+            q.push_back((p, next))
+        }
+    }
+    res
+}
+
+fn longest_walk2(
+    graph: &Graph,
+    end: Pos,
+    route: &mut Vec<Pos>,
+    steps: usize,
+    best: &mut Option<(usize, Vec<Pos>)>,
+) {
+    // With a map of edges, it's now much more efficient to do a simple DFS and find the result.
+    // The junctions on the longest route so far are kept for the visualisation.
+    let here = *route.last().unwrap();
+    if here == end {
+        if best.as_ref().is_none_or(|&(longest, _)| steps > longest) {
+            *best = Some((steps, route.clone()));
+        }
+        return;
+    }
+
+    for &(next, dist) in graph.get(&here).into_iter().flatten() {
+        if !route.contains(&next) {
+            route.push(next);
+            longest_walk2(graph, end, route, steps + dist, best);
+            route.pop();
+        }
+    }
+}
+
+/// The longest hike ignoring slopes, as its length and the junctions along it.
+pub fn longest_hike(grid: &Grid<char>) -> Option<(usize, Vec<Pos>)> {
+    let graph = to_graph(grid);
+
+    debug!(junctions = graph.len(), "built the junction graph");
+    for (from, edges) in &graph {
+        for (to, steps) in edges {
+            trace!(?from, ?to, steps, "path between junctions");
+        }
+    }
+    let mut best = None;
+    longest_walk2(&graph, end(grid), &mut vec![START], 0, &mut best);
+    best
+}
+
+/// The longest hike ignoring slopes.
+pub fn part2(grid: &Grid<char>) -> usize {
+    longest_hike(grid).unwrap().0
+}
+
+/// The forest, with the longest hike ignoring slopes shaded from start to end.
+fn visualise(grid: &Grid<char>) -> Result<Frame> {
+    let Some((_, junctions)) = longest_hike(grid) else {
+        bail!("there is no hike to the end");
+    };
+    // Walk each leg between junctions again to recover the cells along it.
+    let mut path = vec![START];
+    for (&from, &to) in junctions.iter().tuple_windows() {
+        let leg = grid
+            .neighbours4(from)
+            .filter(|&p| grid[p] != '#')
+            .map(|first| corridor(grid, from, first).0)
+            .find(|cells| cells.last() == Some(&to))
+            .unwrap();
+        path.extend(leg);
+    }
+
+    let mut frame = Frame::new(grid, |&c| if c == '#' { viz::GREEN } else { viz::BLACK });
+    frame.path(&path, viz::BLUE, viz::YELLOW);
+    Ok(frame)
+}
+
+/// A forest with a `size` by `size` lattice of junctions, from 2 by 2 up to the puzzle's 6 by 6.
+/// The paths between junctions run right or down, some with a detour to vary their length, and
+/// have slopes at both ends pointing the same way, so that the slopes never lead round in a loop.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    const SPACING: i64 = 10;
+    let k = size.clamp(2, 6) as i64;
+    let junction = |i: i64, j: i64| Pos::new(3 + SPACING * i, 1 + SPACING * j);
+    let last = junction(k - 1, k - 1);
+    let mut grid = Grid::new(last.col as usize + 2, last.row as usize + 6, '#');
+    // The ways in from the top and out at the bottom.
+    for row in 0..3 {
+        grid[Pos::new(row, 1)] = '.';
+    }
+    for row in last.row + 1..=last.row + 5 {
+        grid[Pos::new(row, last.col)] = '.';
+    }
+
+    for (i, j) in (0..k).cartesian_product(0..k) {
+        let Pos { row: r, col: c } = junction(i, j);
+        grid[Pos::new(r, c)] = '.';
+        if j + 1 < k {
+            // Across, perhaps dipping down between columns 5 and 8.
+            let mut path = (1..=5).map(|d| Pos::new(r, c + d)).collect_vec();
+            let depth = rng.gen_range(0..=3);
+            path.extend((1..=depth).map(|d| Pos::new(r + d, c + 5)));
+            path.extend((6..=8).map(|d| Pos::new(r + depth, c + d)));
+            path.extend((0..depth).rev().map(|d| Pos::new(r + d, c + 8)));
+            path.push(Pos::new(r, c + 9));
+            carve(&mut grid, &path, '>');
+        }
+        if i + 1 < k {
+            // Down, perhaps bulging right between rows 5 and 8, except down the right-hand side.
+            let mut path = (1..=5).map(|d| Pos::new(r + d, c)).collect_vec();
+            let depth = if j + 1 < k { rng.gen_range(0..=3) } else { 0 };
+            path.extend((1..=depth).map(|d| Pos::new(r + 5, c + d)));
+            path.extend((6..=8).map(|d| Pos::new(r + d, c + depth)));
+            path.extend((0..depth).rev().map(|d| Pos::new(r + 8, c + d)));
+            path.push(Pos::new(r + 9, c));
+            carve(&mut grid, &path, 'v');
+        }
+    }
+    grid.to_string()
+}
+
+/// Clear a path through the forest, with a slope at each end.
+fn carve(grid: &mut Grid<char>, path: &[Pos], slope: char) {
+    for &pos in path {
+        grid[pos] = '.';
+    }
+    grid[path[0]] = slope;
+    grid[path[path.len() - 1]] = slope;
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+
+    fn visualise(input: &Self::Input, _params: &()) -> Result<Vec<Frame>> {
+        Ok(vec![visualise(input)?])
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(to_graph(input).to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, to_graph, Day23};
+    use crate::answers::expected;
+    use crate::fixtures::{self, example_tests};
+    use crate::graphviz::ToDot;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day23);
+
+    #[test]
+    fn test_dot() {
+        let dot = to_graph(&parse_input(&fixtures::input(2023, 23, "example1").unwrap()).unwrap())
+            .to_dot()
+            .to_string();
+        assert_eq!(
+            dot,
+            r#"graph G {
+    "0,1" [style=filled]
+    "22,21" [style=filled]
+    "0,1" -- "5,3" [label=15]
+    "3,11" -- "5,3" [label=22]
+    "3,11" -- "11,21" [label=30]
+    "3,11" -- "13,13" [label=24]
+    "5,3" -- "13,5" [label=22]
+    "11,21" -- "13,13" [label=18]
+    "11,21" -- "19,19" [label=10]
+    "13,5" -- "13,13" [label=12]
+    "13,5" -- "19,13" [label=38]
+    "13,13" -- "19,13" [label=10]
+    "19,13" -- "19,19" [label=10]
+    "19,19" -- "22,21" [label=5]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 23).unwrap()).unwrap());
+        assert_eq!(Answer::from(res), expected(2023, 23, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(&parse_input(&read_input(2023, 23).unwrap()).unwrap());
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 23, 2));
+    }
+}
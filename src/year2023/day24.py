@@ -8,7 +8,7 @@ from scipy.optimize import fsolve
 
 
 def read_file():
-    with open("../input/day24.txt", "r") as f:
+    with open("../../input/2023/day24.txt", "r") as f:
         lines = f.readlines()
 
     return [
@@ -0,0 +1,273 @@
+//! Day 24: Never Tell Me The Odds. Hailstone paths and the rock thrown to hit them all.
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use ndarray::prelude::*;
+use ndarray_linalg::Solve;
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::debug;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Parameters, Solution};
+
+/// Each hailstone as its position and velocity: `[px, py, pz, vx, vy, vz]`.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<f64>>, ParseError> {
+    parse::lines(Day24::DAY, input)
+        .map(|line| {
+            let fields: [_; 6] = line.split_n(line.split_any(&[',', '@']))?;
+            fields
+                .iter()
+                .map(|s| Ok(s.trim().parse::<i64>()? as f64))
+                .collect()
+        })
+        .collect()
+}
+
+/// Whether two hailstones' paths cross within the test area in the future, ignoring `z`.
+pub fn lines_intersect_2d(l1: &[f64], l2: &[f64], min_pos: f64, max_pos: f64) -> bool {
+    // y = mx - (m*x1-y1), a = m, b = m*x1-y1
+    let x1 = l1[0];
+    let y1 = l1[1];
+    let run1 = l1[3];
+    let rise1 = l1[4];
+    let m1 = rise1 / run1;
+    let a = m1;
+    let c = y1 - m1 * x1;
+
+    let x2 = l2[0];
+    let y2 = l2[1];
+    let run2 = l2[3];
+    let rise2 = l2[4];
+    let m2 = rise2 / run2;
+    let b = m2;
+    let d = y2 - m2 * x2;
+
+    if a == b {
+        false
+    } else {
+        let x = (d - c) / (a - b);
+        let y = a * x + c;
+        min_pos <= x
+            && x <= max_pos
+            && min_pos <= y
+            && y <= max_pos
+            && (x - x1).signum() == run1.signum()
+            && (x - x1).abs() >= run1.abs()
+            && (x - x2).signum() == run2.signum()
+            && (x - x2).abs() >= run2.abs()
+            && (y - y1).signum() == rise1.signum()
+            && (y - y1).abs() >= rise1.abs()
+            && (y - y2).signum() == rise2.signum()
+            && (y - y2).abs() >= rise2.abs()
+    }
+}
+
+/// The pairs of hailstones whose paths cross within the test area.
+pub fn part1(lines: &[Vec<f64>], min_pos: f64, max_pos: f64) -> u64 {
+    let mut count = 0;
+    for i in 0..lines.len() {
+        let l1 = &lines[i];
+        for j in (i + 1)..lines.len() {
+            let l2 = &lines[j];
+            if lines_intersect_2d(l1, l2, min_pos, max_pos) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// The sum of the coordinates of the rock's starting position, worked out from hailstones
+/// `i`, `j` and `k`, or an error if their paths don't pin it down.
+pub fn part2(hail: &[Vec<f64>], i: usize, j: usize, k: usize) -> Result<f64> {
+    if let Some(n) = [i, j, k].into_iter().find(|&n| n >= hail.len()) {
+        bail!("there is no hailstone {n}: there are only {}", hail.len());
+    }
+    let (a0x, a0y, a0z, avx, avy, avz) = (
+        hail[i][0], hail[i][1], hail[i][2], hail[i][3], hail[i][4], hail[i][5],
+    );
+    let (b0x, b0y, b0z, bvx, bvy, bvz) = (
+        hail[j][0], hail[j][1], hail[j][2], hail[j][3], hail[j][4], hail[j][5],
+    );
+    let (c0x, c0y, c0z, cvx, cvy, cvz) = (
+        hail[k][0], hail[k][1], hail[k][2], hail[k][3], hail[k][4], hail[k][5],
+    );
+
+    /*
+    a0x + avx*t = Px + Qx*t
+    a0y + avy*t = Py + Qy*t
+    a0z + avz*t = Pz + Qz*t
+
+    avx*t - Qx*t = Px - a0x
+    t = (Px - a0x) / (avx - Qx)
+    t = (Py - a0y) / (avy - Qy)
+    t = (Pz - a0z) / (avz - Qz)
+
+    (Px - a0x) / (avx - Qx) = (Py - a0y) / (avy - Qy) = (Pz - a0z) / (avz - Qz)
+    (Px - b0x) / (bvx - Qx) =  (Py - b0y) / (bvy - Qy) = (Pz - b0z) / (bvz - Qz)
+    (Px - c0x) / (cvx - Qx) =  (Py - c0y) / (cvy - Qy) = (Pz - c0z) / (cvz - Qz)
+
+    (Px - a0x) * (avy - Qy) = (Py - a0y) * (avx - Qx)
+    Px*avy - Px*Qy - a0x*vy + a0x*Qy = Py*avx - Py*Qx - a0y*avx + a0y*Qx
+
+    Px*Qy - Py*Qx = Px*avy - Py*avx + a0y*avx - a0x*avy + a0x*Qy - a0y*Qx
+    Px*Qy - Py*Qx = Px*bvy - Py*bvx + a0y*bvx - a0x*bvy + b0x*Qy - b0y*Qx
+    Px*Qy - Py*Qx = Px*cvy - Py*cvx + a0y*cvx - a0x*cvy + c0x*Qy - c0y*Qx
+
+    Pz*Qx - Px*Qx = Pz*avx - Px*avz + a0x*avz - a0z*avx + a0z*Qz - a0y*Qy
+    Pz*Qx - Px*Qx = Pz*bvx - Px*bvz + a0x*bvz - a0z*bvx + b0z*Qz - b0y*Qy
+    Pz*Qx - Px*Qx = Pz*cvx - Px*cvz + a0x*cvz - a0z*cvx + c0z*Qz - c0y*Qy
+
+    Py*Qz - Pz*Qy = Py*avz - Pz*avy + a0z*avy - a0y*avz + a0y*Qz - a0z*Qy
+    Py*Qz - Pz*Qy = Py*bvz - Pz*bvy + a0z*bvy - a0y*bvz + b0y*Qz - b0z*Qy
+    Py*Qz - Pz*Qy = Py*cvz - Pz*cvy + a0z*cvy - a0y*cvz + c0y*Qz - c0z*Qy
+
+    (avy - bvy)*Px + (avx - bvx)*Py +                + (a0y - b0y)*Qx + (a0x - b0x)*Qy                  = (b0y*bvx - b0x*bvy) + (a0y*avx - a0x*avy)
+    (avy - cvy)*Px + (avx - cvx)*Py +                + (a0y - c0y)*Qx + (a0x - c0x)*Qy                  = (c0y*cvx - c0x*cvy) + (a0y*avx - a0x*avy)
+    (avz - bvz)*Px +                + (avx - bvx)*Pz + (a0z - b0z)*Qx +                + (a0x - b0x)*Qz = (b0x*bvz - b0z*bvx) + (a0x*avz - a0y*avz)
+    (avz - cvz)*Px +                + (avx - cvx)*Pz + (a0z - b0z)*Qx +                + (a0x - c0x)*Qz = (c0x*cvz - c0z*cvx) + (a0x*avz - a0y*avz)
+                   + (avz - bvz)*Py + (avy - bvy)*Pz +                + (a0z - b0z)*Qy + (a0y - c0y)*Qz = (b0z*bvy - b0y*bvz) + (a0z*avy - a0y*avz)
+                   + (avz - cvz)*Py + (avy - cvy)*Pz +                + (a0z - c0z)*Qy + (a0y - c0y)*Qz = (c0z*cvy - c0y*cvz) + (a0z*avy - a0y*avz)
+     */
+    let a = array![
+        [avy - bvy, bvx - avx, 0.0, b0y - a0y, a0x - b0x, 0.0],
+        [avy - cvy, cvx - avx, 0.0, c0y - a0y, a0x - c0x, 0.0],
+        [bvz - avz, 0.0, avx - bvx, a0z - b0z, 0.0, b0x - a0x],
+        [cvz - avz, 0.0, avx - cvx, a0z - c0z, 0.0, c0x - a0x],
+        [0.0, avz - bvz, bvy - avy, 0.0, b0z - a0z, a0y - b0y],
+        [0.0, avz - cvz, cvy - avy, 0.0, c0z - a0z, a0y - c0y],
+    ];
+    let b = array![
+        (b0y * bvx - b0x * bvy) - (a0y * avx - a0x * avy),
+        (c0y * cvx - c0x * cvy) - (a0y * avx - a0x * avy),
+        (b0x * bvz - b0z * bvx) - (a0x * avz - a0z * avx),
+        (c0x * cvz - c0z * cvx) - (a0x * avz - a0z * avx),
+        (b0z * bvy - b0y * bvz) - (a0z * avy - a0y * avz),
+        (c0z * cvy - c0y * cvz) - (a0z * avy - a0y * avz),
+    ];
+    let res = a
+        .solve(&b)
+        .with_context(|| format!("hailstones {i}, {j} and {k} don't pin down the rock"))?;
+    debug!(position = %res.slice(s![..3]), velocity = %res.slice(s![3..]), "solved for the rock");
+    Ok(res[0] + res[1] + res[2])
+}
+
+/// The test area for part 1 and the three hailstones whose paths pin down the rock in part 2.
+pub struct Params {
+    pub min_pos: f64,
+    pub max_pos: f64,
+    pub hailstones: [usize; 3],
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            min_pos: 200000000000000.,
+            max_pos: 400000000000000.,
+            hailstones: [1, 10, 20],
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "min_pos" => self.min_pos = value.parse()?,
+            "max_pos" => self.max_pos = value.parse()?,
+            "hailstones" => {
+                let Some((i, j, k)) = value.split(",").map(|s| s.trim().parse()).collect_tuple()
+                else {
+                    bail!("expected three comma separated hailstones, got `{value}`");
+                };
+                self.hailstones = [i?, j?, k?];
+            }
+            _ => bail!("unknown parameter `{key}`"),
+        }
+        Ok(())
+    }
+}
+
+/// At least 21 hailstones, `size` if more, since part 2 uses the 21st by default. A rock is
+/// thrown first, and then each hailstone is placed so that the rock hits it at its own time.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let rock_velocity = [(); 3].map(|_| rng.gen_range(-300..=300i64));
+    let rock = [(); 3].map(|_| rng.gen_range(250_000_000_000_000..350_000_000_000_000i64));
+
+    let mut res = String::new();
+    for _ in 0..size.max(21) {
+        let time = rng.gen_range(10_000_000_000..1_000_000_000_000i64);
+        // No component may match the rock's, so no hailstone starts level with it along an axis.
+        let velocity = rock_velocity.map(|v| loop {
+            let velocity = rng.gen_range(-300..=300i64);
+            if velocity != v {
+                break velocity;
+            }
+        });
+        let [x, y, z] = [0, 1, 2].map(|i| rock[i] + (rock_velocity[i] - velocity[i]) * time);
+        let [vx, vy, vz] = velocity;
+        res.push_str(&format!("{x}, {y}, {z} @ {vx}, {vy}, {vz}\n"));
+    }
+    res
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input = Vec<Vec<f64>>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        Ok(part1(input, params.min_pos, params.max_pos).into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let [i, j, k] = params.hailstones;
+        Ok((part2(input, i, j, k)?.round() as i64).into())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, part2, Day24};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day24);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(
+            &parse_input(&read_input(2023, 24).unwrap()).unwrap(),
+            200000000000000.,
+            400000000000000.,
+        );
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 24, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let res = part2(
+            &parse_input(&read_input(2023, 24).unwrap()).unwrap(),
+            1,
+            10,
+            20,
+        )
+        .unwrap();
+        println!("{}", res);
+        assert_eq!(res.fract(), 0.);
+        assert_eq!(Answer::from(res as i64), expected(2023, 24, 2));
+    }
+}
@@ -0,0 +1,172 @@
+//! Day 25: Snowverload. Cutting three wires to split the components in two.
+
+use rustworkx_core::connectivity::stoer_wagner_min_cut;
+use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::gen;
+use crate::graphviz::{Dot, ToDot};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// The components each component is wired to.
+pub fn parse_input(input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let mut res = HashMap::new();
+    for line in parse::lines(Day25::DAY, input) {
+        let (lhs, rhs) = line.split_once(": ")?;
+        let rhs = rhs
+            .split_whitespace()
+            .map(|s| s.text().to_string())
+            .collect_vec();
+        res.insert(lhs.text().to_string(), rhs);
+    }
+    Ok(res)
+}
+
+fn node_for_label(
+    rhs: &String,
+    g: &mut UnGraph<(), ()>,
+    nodes: &mut HashMap<String, NodeIndex>,
+) -> NodeIndex {
+    if let Some(&node) = nodes.get(rhs) {
+        node
+    } else {
+        let node = g.add_node(());
+        nodes.insert(rhs.clone(), node);
+        node
+    }
+}
+
+/// The product of the sizes of the two groups left by cutting three wires, or an error if three
+/// is not the fewest wires to cut.
+pub fn part1(graph: &HashMap<String, Vec<String>>) -> Result<usize> {
+    // Turns out there's an algorithm to find the min edge cut of a connected graph required
+    // to partition the graph into to parts. Call that wonderful algorithm to confirm that the
+    // min edge cut is indeed 3. Then compute the size of the two partitions.
+    //
+    // How we were expected to write this ourselves, I have no idea. It's a pretty complex algorithm.
+
+    let mut edges = Vec::new();
+    let mut nodes = HashMap::new();
+    let mut g: UnGraph<(), ()> = UnGraph::new_undirected();
+    for (rhs, lhs) in graph {
+        let rhs_node = node_for_label(rhs, &mut g, &mut nodes);
+        for n in lhs {
+            let lhs_node = node_for_label(n, &mut g, &mut nodes);
+            edges.push((rhs_node, lhs_node));
+        }
+    }
+    g.extend_with_edges(edges);
+
+    let min_cut_res: rustworkx_core::Result<Option<(usize, Vec<_>)>> =
+        stoer_wagner_min_cut(&g, |_| Ok(1));
+    let (min_cut, partition) =
+        min_cut_res?.ok_or_else(|| anyhow!("there are too few components to split"))?;
+    if min_cut != 3 {
+        bail!("cutting {min_cut} wires, not 3, splits the components in two");
+    }
+    let p1 = partition.len();
+    let p2 = nodes.len() - p1;
+    Ok(p1 * p2)
+}
+
+/// The wiring, with each wire labelled by the components it joins so the three to cut can be
+/// read off a drawing, e.g. with `neato`.
+impl ToDot for HashMap<String, Vec<String>> {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::graph();
+        for (lhs, rhs) in self.iter().sorted() {
+            for n in rhs {
+                dot.edge(lhs, n, &[("label", &format!("{lhs}_{n}"))]);
+            }
+        }
+        dot
+    }
+}
+
+/// Two groups of at least five components, `size` if more, joined by exactly three wires. Each
+/// group is wired in a ring to its two nearest neighbours either way, plus some wires across, so
+/// it takes at least four cuts to split one and the three wires between them are the only cut
+/// of three.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let n = size.max(5);
+    let names = gen::names(rng, 2 * n, 3, &[]);
+    let mut wires = BTreeSet::new();
+    let mut wire = |a: usize, b: usize| wires.insert((a.min(b), a.max(b)));
+    for group in [0, n] {
+        for i in 0..n {
+            wire(group + i, group + (i + 1) % n);
+            wire(group + i, group + (i + 2) % n);
+        }
+        for _ in 0..n / 2 {
+            let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            if a != b {
+                wire(group + a, group + b);
+            }
+        }
+    }
+    let cut = rand::seq::index::sample(rng, n, 3).into_vec();
+    wires.extend(cut.iter().map(|&a| (a, n + rng.gen_range(0..n))));
+
+    // Each wire is listed once, by whichever end comes first.
+    let mut lines: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (lhs, rhs) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(&names[lhs]).or_default().push(&names[rhs]);
+    }
+    let mut lines = lines
+        .into_iter()
+        .map(|(lhs, rhs)| format!("{lhs}: {}", rhs.join(" ")))
+        .collect_vec();
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const HAS_PART2: bool = false;
+    type Input = HashMap<String, Vec<String>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &()) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn dot(input: &Self::Input, _params: &()) -> Result<Dot> {
+        Ok(input.to_dot())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, part1, Day25};
+    use crate::answers::expected;
+    use crate::fixtures::example_tests;
+    use crate::input::read_input;
+    use crate::solution::Answer;
+
+    example_tests!(2023, Day25);
+
+    #[test]
+    fn test_part1() {
+        let res = part1(&parse_input(&read_input(2023, 25).unwrap()).unwrap()).unwrap();
+        println!("{}", res);
+        assert_eq!(Answer::from(res), expected(2023, 25, 1));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::solution::{AnySolution, Erased};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// The dispatch table, in day order. `aoc new` adds entries here.
+#[rustfmt::skip]
+pub(crate) static SOLUTIONS: &[&dyn AnySolution] = &[
+    &Erased::<day01::Day01>::new(),
+    &Erased::<day02::Day02>::new(),
+    &Erased::<day03::Day03>::new(),
+    &Erased::<day04::Day04>::new(),
+    &Erased::<day05::Day05>::new(),
+    &Erased::<day06::Day06>::new(),
+    &Erased::<day07::Day07>::new(),
+    &Erased::<day08::Day08>::new(),
+    &Erased::<day09::Day09>::new(),
+    &Erased::<day10::Day10>::new(),
+    &Erased::<day11::Day11>::new(),
+    &Erased::<day12::Day12>::new(),
+    &Erased::<day13::Day13>::new(),
+    &Erased::<day14::Day14>::new(),
+    &Erased::<day15::Day15>::new(),
+    &Erased::<day16::Day16>::new(),
+    &Erased::<day17::Day17>::new(),
+    &Erased::<day18::Day18>::new(),
+    &Erased::<day19::Day19>::new(),
+    &Erased::<day20::Day20>::new(),
+    &Erased::<day21::Day21>::new(),
+    &Erased::<day22::Day22>::new(),
+    &Erased::<day23::Day23>::new(),
+    &Erased::<day24::Day24>::new(),
+    &Erased::<day25::Day25>::new(),
+];